@@ -0,0 +1,132 @@
+use crate::{
+    api::season_param,
+    client::StatbookClient,
+    error::Result,
+    models::{Boxscore, Game, Play, ScheduleFilter, Season},
+};
+
+pub async fn get_schedule(
+    client: &StatbookClient,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+    filter: &ScheduleFilter,
+) -> Result<Vec<Game>> {
+    let season_param = season_param(year_range, season);
+    let games = client
+        .stats_provider()
+        .fetch_schedule(&season_param)
+        .await?;
+
+    Ok(games
+        .into_iter()
+        .filter(|game| filter.matches(game))
+        .collect())
+}
+
+pub async fn get_game_boxscore(
+    client: &StatbookClient,
+    game_id: u64,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+) -> Result<Boxscore> {
+    let season_param = season_param(year_range, season);
+
+    client
+        .stats_provider()
+        .fetch_game_boxscore(game_id, &season_param)
+        .await
+}
+
+pub async fn get_play_by_play(
+    client: &StatbookClient,
+    game_id: u64,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+) -> Result<Vec<Play>> {
+    let season_param = season_param(year_range, season);
+
+    client
+        .stats_provider()
+        .fetch_play_by_play(game_id, &season_param)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GameStatus;
+    use crate::test_utils::create_mock_client;
+    use crate::StatbookError;
+    use chrono::NaiveDate;
+
+    #[tokio::test]
+    async fn test_get_schedule_mock() {
+        let client = create_mock_client();
+
+        let all = get_schedule(&client, None, &Season::Regular, &ScheduleFilter::new())
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 4);
+        assert!(all.windows(2).all(|w| w[0].start_time <= w[1].start_time));
+
+        let week_two = ScheduleFilter::new().with_week(2).with_team("BUF");
+        let games = get_schedule(&client, None, &Season::Regular, &week_two)
+            .await
+            .unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].winner(), Some("BUF"));
+
+        let september_end = ScheduleFilter::new().with_date_range(
+            NaiveDate::from_ymd_opt(2024, 9, 20).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 9, 30).unwrap(),
+        );
+        let games = get_schedule(&client, None, &Season::Regular, &september_end)
+            .await
+            .unwrap();
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[1].status, GameStatus::Scheduled);
+        assert!(games[1].score.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_game_boxscore_mock() {
+        let client = create_mock_client();
+        let boxscore = get_game_boxscore(&client, 133401, None, &Season::Regular)
+            .await
+            .unwrap();
+
+        assert_eq!(boxscore.game.home_team, "BUF");
+        assert_eq!(
+            boxscore.quarters.iter().map(|q| q.home).sum::<u32>(),
+            boxscore.home.points
+        );
+        let allen = boxscore.home.player_by_id(7549).unwrap();
+        assert_eq!(allen.last_name, "Allen");
+        assert_eq!(allen.passing.as_ref().unwrap().yards, 232);
+
+        let missing = get_game_boxscore(&client, 1, Some((2023, 2024)), &Season::Regular).await;
+        assert!(matches!(
+            missing,
+            Err(StatbookError::GameNotFound { game_id: 1 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_play_by_play_mock() {
+        let client = create_mock_client();
+        let plays = get_play_by_play(&client, 133401, None, &Season::Regular)
+            .await
+            .unwrap();
+
+        assert_eq!(plays.len(), 2);
+        assert!(plays.windows(2).all(|w| w[0].sequence < w[1].sequence));
+        assert!(!plays[0].is_red_zone() && plays[1].is_red_zone());
+        assert!(plays[1].scoring);
+
+        let missing = get_play_by_play(&client, 1, Some((2023, 2024)), &Season::Regular).await;
+        assert!(matches!(
+            missing,
+            Err(StatbookError::GameNotFound { game_id: 1 })
+        ));
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{client::StatbookClient, error::Result, models::InjuredPlayer};
+
+pub async fn get_injury_report(
+    client: &StatbookClient,
+    team: Option<&str>,
+) -> Result<Vec<InjuredPlayer>> {
+    let team = team.map(|team| team.trim().to_lowercase());
+
+    client
+        .stats_provider()
+        .fetch_injuries(team.as_deref())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::InjuryStatus;
+    use crate::test_utils::create_mock_client;
+
+    #[tokio::test]
+    async fn test_get_injury_report_mock() {
+        let client = create_mock_client();
+
+        let league = get_injury_report(&client, None).await.unwrap();
+        assert_eq!(league.len(), 2);
+
+        let bills = get_injury_report(&client, Some("BUF")).await.unwrap();
+        assert_eq!(bills.len(), 1);
+        assert_eq!(bills[0].player.last_name, "Kincaid");
+        assert_eq!(bills[0].injury.status, InjuryStatus::Questionable);
+        assert!(!bills[0].injury.status.is_out());
+    }
+}
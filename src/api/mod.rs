@@ -1 +1,17 @@
+use crate::models::Season;
+
+pub mod games;
+pub mod injuries;
+pub mod news;
+pub mod odds;
 pub mod players;
+pub mod teams;
+pub mod transactions;
+
+/// Builds the provider season identifier (e.g., "2023-2024-regular").
+pub(crate) fn season_param(year_range: Option<(i64, i64)>, season: &Season) -> String {
+    match year_range {
+        Some((start, end)) => season.format_with_years(start, end),
+        None => season.as_str().to_string(),
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    client::StatbookClient,
+    error::{Result, StatbookError},
+    models::{Article, NewsQuery},
+    utils::nfl_team_name,
+};
+use futures::stream::{self, Stream, StreamExt};
+
+pub async fn get_team_news(client: &StatbookClient, team: &str) -> Result<Vec<Article>> {
+    let team = team.trim();
+    let team = nfl_team_name(team).unwrap_or(team);
+
+    client
+        .news_provider()
+        .fetch_team_news(&client.news_query(team))
+        .await
+}
+
+pub async fn get_headlines(client: &StatbookClient) -> Result<Vec<Article>> {
+    client
+        .news_provider()
+        .fetch_headlines(client.news_config().max_articles)
+        .await
+}
+
+/// Streams every article matching a query, fetching pages lazily.
+///
+/// Starts at `query.page` and requests the next page only once the previous
+/// one has been consumed. Stops once the provider's total count is reached
+/// (or at an empty page if no total is reported), or after
+/// `NewsConfig::max_pages` pages, or when the provider refuses to page past
+/// its result limit. Any other failed request is yielded as an error and ends
+/// the stream.
+pub fn news_stream<'a>(
+    client: &'a StatbookClient,
+    query: &NewsQuery,
+) -> impl Stream<Item = Result<Article>> + 'a {
+    let max_pages = client.news_config().max_pages;
+
+    stream::unfold(Some((query.clone(), 0)), move |state| async move {
+        let (query, pages_fetched) = state?;
+        if pages_fetched >= max_pages {
+            return None;
+        }
+
+        let news = match client.news_provider().fetch_player_news(&query).await {
+            Ok(news) => news,
+            // Earlier pages are kept; running into the provider's result limit
+            // just means there is nothing more it will serve
+            Err(StatbookError::NewsResultLimit { .. }) if pages_fetched > 0 => return None,
+            Err(error) => return Some((vec![Err(error)], None)),
+        };
+
+        // Providers may drop malformed articles from a page, so a short page
+        // does not mean the results ran out; rely on the reported total instead
+        let fetched_through = query.page as usize * query.page_size as usize;
+        let exhausted = match news.total_count {
+            Some(total) => fetched_through >= total as usize,
+            None => news.is_empty(),
+        };
+        let next = if exhausted {
+            None
+        } else {
+            let page = query.page + 1;
+            Some((query.with_page(page), pages_fetched + 1))
+        };
+
+        Some((news.articles.into_iter().map(Ok).collect(), next))
+    })
+    .flat_map(stream::iter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::NewsConfig,
+        providers::{MockNewsProvider, MockStatsProvider},
+        test_utils::{create_custom_mock_client, create_mock_client},
+    };
+    use chrono::{TimeZone, Utc};
+    use futures::TryStreamExt;
+
+    fn archive_articles(count: u32) -> Vec<Article> {
+        (0..count)
+            .map(|i| Article {
+                title: format!("Article {}", i),
+                description: String::new(),
+                url: format!("https://example.com/news/{}", i),
+                source_id: None,
+                source_name: "Example".to_string(),
+                author: None,
+                image_url: None,
+                published_at: Utc.with_ymd_and_hms(2024, 9, 1, 12, 0, 0).unwrap(),
+                content: String::new(),
+            })
+            .collect()
+    }
+
+    fn archive_client(articles: u32) -> StatbookClient {
+        let mut news_provider = MockNewsProvider::new();
+        news_provider.add_news_articles("josh-allen", archive_articles(articles));
+
+        create_custom_mock_client(MockStatsProvider::new(), news_provider)
+    }
+
+    #[tokio::test]
+    async fn test_get_team_news_mock() {
+        let client = create_mock_client();
+
+        let by_abbreviation = get_team_news(&client, " buf ").await.unwrap();
+        assert_eq!(by_abbreviation.len(), 1);
+        assert!(by_abbreviation[0].title.contains("Cooper"));
+
+        let by_name = get_team_news(&client, "Buffalo Bills").await.unwrap();
+        assert_eq!(by_name[0].url, by_abbreviation[0].url);
+
+        assert!(get_team_news(&client, "MIA").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_team_news_uses_client_news_config() {
+        let mut news_provider = MockNewsProvider::new();
+        news_provider.add_team_news("Buffalo Bills", archive_articles(3));
+        let client = create_custom_mock_client(MockStatsProvider::new(), news_provider);
+        assert_eq!(get_team_news(&client, "BUF").await.unwrap().len(), 3);
+
+        let client = client.with_news_config(NewsConfig::new().with_max_articles(2));
+        assert_eq!(get_team_news(&client, "BUF").await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_headlines_mock() {
+        let client = create_mock_client();
+        let headlines = get_headlines(&client).await.unwrap();
+        assert_eq!(headlines.len(), 2);
+
+        let client = client.with_news_config(NewsConfig::new().with_max_articles(1));
+        assert_eq!(get_headlines(&client).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_news_stream_walks_pages() {
+        let client = archive_client(12);
+        let query = NewsQuery::for_player("josh-allen").with_page_size(5);
+
+        let articles: Vec<Article> = news_stream(&client, &query).try_collect().await.unwrap();
+        assert_eq!(articles.len(), 12);
+        assert_eq!(articles[0].title, "Article 0");
+        assert_eq!(articles[11].title, "Article 11");
+
+        let from_second_page: Vec<Article> = news_stream(&client, &query.clone().with_page(2))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(from_second_page[0].title, "Article 5");
+    }
+
+    #[tokio::test]
+    async fn test_news_stream_continues_past_short_pages() {
+        let mut news_provider = MockNewsProvider::new();
+        news_provider.add_news_articles("josh-allen", archive_articles(12));
+        // The first two pages each come back one article short
+        news_provider.mark_undated("josh-allen", vec![4, 9]);
+        let client = create_custom_mock_client(MockStatsProvider::new(), news_provider);
+
+        let query = NewsQuery::for_player("josh-allen").with_page_size(5);
+        let articles: Vec<Article> = news_stream(&client, &query).try_collect().await.unwrap();
+        assert_eq!(articles.len(), 10);
+        assert_eq!(articles.last().unwrap().title, "Article 11");
+    }
+
+    #[tokio::test]
+    async fn test_news_stream_ends_at_result_limit() {
+        let mut news_provider = MockNewsProvider::new();
+        news_provider.add_news_articles("josh-allen", archive_articles(250));
+        news_provider.set_result_limit(100);
+        let client = create_custom_mock_client(MockStatsProvider::new(), news_provider)
+            .with_news_config(NewsConfig::new().with_max_pages(10));
+
+        // Pages of 30 fit three times under a limit of 100
+        let query = NewsQuery::for_player("josh-allen").with_page_size(30);
+        let articles: Vec<Article> = news_stream(&client, &query).try_collect().await.unwrap();
+        assert_eq!(articles.len(), 90);
+
+        // Starting past the limit still reports the error
+        let past_limit: Vec<Result<Article>> =
+            news_stream(&client, &query.with_page(5)).collect().await;
+        assert_eq!(past_limit.len(), 1);
+        assert!(matches!(
+            past_limit[0],
+            Err(StatbookError::NewsResultLimit { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_news_stream_respects_page_cap() {
+        let client = archive_client(100).with_news_config(NewsConfig::new().with_max_pages(3));
+        let query = NewsQuery::for_player("josh-allen").with_page_size(10);
+
+        let articles: Vec<Article> = news_stream(&client, &query).try_collect().await.unwrap();
+        assert_eq!(articles.len(), 30);
+
+        let unknown = NewsQuery::for_player("unknown-player");
+        let none: Vec<Article> = news_stream(&create_mock_client(), &unknown)
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(none.is_empty());
+    }
+}
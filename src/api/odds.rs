@@ -0,0 +1,70 @@
+use crate::{
+    api::season_param,
+    client::StatbookClient,
+    error::Result,
+    models::{GameLine, PlayerProp, Season},
+};
+
+pub async fn get_game_lines(
+    client: &StatbookClient,
+    game_id: u64,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+) -> Result<Vec<GameLine>> {
+    let season_param = season_param(year_range, season);
+
+    client
+        .stats_provider()
+        .fetch_game_lines(game_id, &season_param)
+        .await
+}
+
+pub async fn get_player_props(
+    client: &StatbookClient,
+    game_id: u64,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+) -> Result<Vec<PlayerProp>> {
+    let season_param = season_param(year_range, season);
+
+    client
+        .stats_provider()
+        .fetch_player_props(game_id, &season_param)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_mock_client;
+
+    #[tokio::test]
+    async fn test_get_game_lines_mock() {
+        let client = create_mock_client();
+        let lines = get_game_lines(&client, 133404, None, &Season::Regular)
+            .await
+            .unwrap();
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].book, "FanDuel");
+        assert_eq!(lines[0].implied_home_total(), Some(24.5));
+        assert_eq!(lines[0].implied_away_total(), Some(22.0));
+
+        let settled = get_game_lines(&client, 133401, Some((2024, 2025)), &Season::Regular)
+            .await
+            .unwrap();
+        assert!(settled.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_player_props_mock() {
+        let client = create_mock_client();
+        let props = get_player_props(&client, 133404, None, &Season::Regular)
+            .await
+            .unwrap();
+
+        assert_eq!(props.len(), 1);
+        assert_eq!(props[0].market, "passing_yards");
+        assert_eq!(props[0].line, 238.5);
+    }
+}
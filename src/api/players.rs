@@ -1,7 +1,11 @@
 use crate::{
+    api::season_param,
     client::StatbookClient,
-    error::Result,
-    models::{NewsQuery, PlayerNews, PlayerStats, PlayerSummary, Season},
+    error::{Result, StatbookError},
+    models::{
+        GameLog, NewsQuery, PlayerDirectory, PlayerHint, PlayerMatch, PlayerNews, PlayerProfile,
+        PlayerStats, PlayerSummary, Season,
+    },
     utils::to_dash_case,
 };
 
@@ -12,11 +16,7 @@ pub async fn get_player_stats(
     season: &Season,
 ) -> Result<PlayerStats> {
     let dash_name = to_dash_case(name);
-
-    let season_param: String = match year_range {
-        Some((start, end)) => season.format_with_years(start, end),
-        None => season.as_str().to_string(),
-    };
+    let season_param = season_param(year_range, season);
 
     client
         .stats_provider()
@@ -24,6 +24,82 @@ pub async fn get_player_stats(
         .await
 }
 
+pub async fn get_player_stats_with_hint(
+    client: &StatbookClient,
+    name: &str,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+    hint: &PlayerHint,
+) -> Result<PlayerStats> {
+    let dash_name = to_dash_case(name);
+    let season_param = season_param(year_range, season);
+
+    client
+        .stats_provider()
+        .fetch_player_stats_with_hint(&dash_name, &season_param, hint)
+        .await
+}
+
+pub async fn get_player_stats_by_id(
+    client: &StatbookClient,
+    player_id: u64,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+) -> Result<PlayerStats> {
+    let season_param = season_param(year_range, season);
+
+    client
+        .stats_provider()
+        .fetch_player_stats_by_id(player_id, &season_param)
+        .await
+}
+
+pub async fn get_player_gamelogs(
+    client: &StatbookClient,
+    name: &str,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+    week_range: Option<(u32, u32)>,
+) -> Result<Vec<GameLog>> {
+    if let Some((first, last)) = week_range {
+        if first > last {
+            return Err(StatbookError::Validation(format!(
+                "Week range starts after it ends: {first}-{last}"
+            )));
+        }
+    }
+
+    let dash_name = to_dash_case(name);
+    let season_param = season_param(year_range, season);
+
+    client
+        .stats_provider()
+        .fetch_player_gamelogs(&dash_name, &season_param, week_range)
+        .await
+}
+
+pub async fn get_player_profile(client: &StatbookClient, name: &str) -> Result<PlayerProfile> {
+    let dash_name = to_dash_case(name);
+
+    client
+        .stats_provider()
+        .fetch_player_profile(&dash_name)
+        .await
+}
+
+pub async fn get_player_directory(client: &StatbookClient) -> Result<PlayerDirectory> {
+    let players = client.stats_provider().fetch_players().await?;
+    Ok(PlayerDirectory::new(players))
+}
+
+/// Maximum number of candidates returned by `search_players`.
+const MAX_SEARCH_RESULTS: usize = 10;
+
+pub async fn search_players(client: &StatbookClient, query: &str) -> Result<Vec<PlayerMatch>> {
+    let index = client.player_index().await?;
+    Ok(index.search(query, MAX_SEARCH_RESULTS))
+}
+
 pub async fn get_player_news(client: &StatbookClient, query: &NewsQuery) -> Result<PlayerNews> {
     client.news_provider().fetch_player_news(query).await
 }
@@ -35,11 +111,8 @@ pub async fn get_player_summary(
     season: &Season,
 ) -> Result<PlayerSummary> {
     let dash_name = to_dash_case(name);
-    let query = NewsQuery::for_player(&dash_name);
-    let season_param: String = match year_range {
-        Some((start, end)) => season.format_with_years(start, end),
-        None => season.as_str().to_string(),
-    };
+    let query = client.news_query(&dash_name);
+    let season_param = season_param(year_range, season);
 
     let (stats_result, news_result) = tokio::join!(
         client
@@ -52,6 +125,8 @@ pub async fn get_player_summary(
     let news = news_result.map(|n| n.articles).unwrap_or_default();
 
     Ok(PlayerSummary {
+        player_id: stats.player_id,
+        team_id: stats.team_id,
         first_name: stats.first_name,
         last_name: stats.last_name,
         primary_position: stats.primary_position,
@@ -81,6 +156,166 @@ mod tests {
         assert_eq!(stats.season, "regular");
     }
 
+    #[tokio::test]
+    async fn test_get_player_stats_passing_line_mock() {
+        let client = create_mock_client();
+        let stats = get_player_stats(&client, "josh-allen", None, &Season::Regular)
+            .await
+            .unwrap();
+
+        let passing = stats.passing.expect("QB should have a passing line");
+        assert_eq!(passing.yards, 3731);
+        assert_eq!(passing.touchdowns, 28);
+        assert_eq!(passing.interceptions, 6);
+
+        let rushing = stats.rushing.expect("Allen should have a rushing line");
+        assert_eq!(rushing.touchdowns, 12);
+        assert!(stats.receiving.is_none());
+
+        let usage = stats.usage.expect("Allen should have snap counts");
+        assert_eq!(usage.games_started_ratio(), Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn test_get_player_stats_by_id_mock() {
+        let client = create_mock_client();
+        let by_name = get_player_stats(&client, "josh-allen", None, &Season::Regular)
+            .await
+            .unwrap();
+        let by_id = get_player_stats_by_id(&client, by_name.player_id, None, &Season::Regular)
+            .await
+            .unwrap();
+
+        assert_eq!(by_id.player_id, 7549);
+        assert_eq!(by_id.team_id, Some(48));
+        assert_eq!(by_id.first_name, "Josh");
+        assert_eq!(by_id.season, "regular");
+
+        let summary = get_player_summary(&client, "josh-allen", None, &Season::Regular)
+            .await
+            .unwrap();
+        assert_eq!(summary.player_id, by_name.player_id);
+
+        let missing = get_player_stats_by_id(&client, 1, None, &Season::Regular).await;
+        assert!(matches!(
+            missing,
+            Err(crate::StatbookError::PlayerNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_ambiguous_player_with_hint_mock() {
+        use crate::providers::{MockNewsProvider, MockStatsProvider};
+        use crate::test_utils::create_custom_mock_client;
+        use crate::StatbookError;
+
+        let mut stats_provider = MockStatsProvider::with_defaults();
+        stats_provider.add_player_stats(
+            "josh-allen",
+            PlayerStats {
+                player_id: 8102,
+                first_name: "Josh".to_string(),
+                last_name: "Allen".to_string(),
+                primary_position: "LB".to_string(),
+                current_team: "JAX".to_string(),
+                ..Default::default()
+            },
+        );
+        let client = create_custom_mock_client(stats_provider, MockNewsProvider::new());
+
+        match get_player_stats(&client, "Josh Allen", None, &Season::Regular).await {
+            Err(StatbookError::AmbiguousPlayer { name, candidates }) => {
+                assert_eq!(name, "josh-allen");
+                let ids: Vec<u64> = candidates.iter().map(|c| c.player_id).collect();
+                assert_eq!(ids, vec![7549, 8102]);
+            }
+            other => panic!("expected AmbiguousPlayer, got {other:?}"),
+        }
+
+        let hint = PlayerHint::new().with_team("JAX");
+        let linebacker =
+            get_player_stats_with_hint(&client, "Josh Allen", None, &Season::Regular, &hint)
+                .await
+                .unwrap();
+        assert_eq!(linebacker.player_id, 8102);
+        assert_eq!(linebacker.season, "regular");
+    }
+
+    #[tokio::test]
+    async fn test_search_players_mock() {
+        let client = create_mock_client();
+
+        let matches = search_players(&client, "josh alen").await.unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].profile.player_id, 7549);
+        assert!(matches[0].score > 0.8);
+
+        // Retired players are not part of the active index
+        let matches = search_players(&client, "Tom Brady").await.unwrap();
+        assert!(matches.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_player_directory_mock() {
+        let client = create_mock_client();
+        let directory = get_player_directory(&client).await.unwrap();
+        assert_eq!(directory.len(), 2);
+
+        let buffalo_qbs = directory.clone().by_team("buf").by_position("QB");
+        assert_eq!(buffalo_qbs.len(), 1);
+        assert_eq!(buffalo_qbs.players[0].last_name, "Allen");
+
+        let retired = directory.clone().by_roster_status("RETIRED");
+        assert_eq!(retired.players[0].last_name, "Brady");
+
+        assert_eq!(directory.clone().active().len(), 1);
+        assert!(directory.rookies().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_player_gamelogs_mock() {
+        let client = create_mock_client();
+
+        let all = get_player_gamelogs(&client, "josh-allen", None, &Season::Regular, None)
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 3);
+
+        let later =
+            get_player_gamelogs(&client, "josh-allen", None, &Season::Regular, Some((2, 3)))
+                .await
+                .unwrap();
+        let weeks: Vec<u32> = later.iter().map(|gamelog| gamelog.week).collect();
+        assert_eq!(weeks, vec![2, 3]);
+        assert_eq!(later[0].opponent, "MIA");
+        assert_eq!(later[0].home_away, crate::HomeAway::Away);
+        assert_eq!(later[1].passing.as_ref().unwrap().touchdowns, 4);
+
+        let reversed =
+            get_player_gamelogs(&client, "josh-allen", None, &Season::Regular, Some((3, 1))).await;
+        assert!(matches!(reversed, Err(crate::StatbookError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_player_profile_mock() {
+        let client = create_mock_client();
+        let profile = get_player_profile(&client, "Josh Allen").await.unwrap();
+
+        assert_eq!(profile.player_id, 7549);
+        assert_eq!(profile.height_inches, Some(77));
+        assert_eq!(profile.college.as_deref(), Some("Wyoming"));
+        assert_eq!(
+            profile.birth_date,
+            chrono::NaiveDate::from_ymd_opt(1996, 5, 21)
+        );
+
+        let missing = get_player_profile(&client, "unknown-player").await;
+        assert!(matches!(
+            missing,
+            Err(crate::StatbookError::PlayerNotFound { .. })
+        ));
+    }
+
     #[tokio::test]
     async fn test_get_player_news_mock() {
         let client = create_mock_client();
@@ -0,0 +1,132 @@
+use crate::{
+    api::season_param,
+    client::StatbookClient,
+    error::{Result, StatbookError},
+    models::{DepthChart, GameOrWeek, Season, Standings, TeamStats},
+};
+
+pub async fn get_team_stats(
+    client: &StatbookClient,
+    team_abbr: &str,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+) -> Result<TeamStats> {
+    let team = team_abbr.trim().to_lowercase();
+    let season_param = season_param(year_range, season);
+
+    client
+        .stats_provider()
+        .fetch_team_stats(&team, &season_param)
+        .await
+}
+
+pub async fn get_standings(
+    client: &StatbookClient,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+) -> Result<Standings> {
+    let season_param = season_param(year_range, season);
+
+    client.stats_provider().fetch_standings(&season_param).await
+}
+
+pub async fn get_depth_chart(
+    client: &StatbookClient,
+    team_abbr: &str,
+    game_or_week: GameOrWeek,
+    year_range: Option<(i64, i64)>,
+    season: &Season,
+) -> Result<DepthChart> {
+    let team = team_abbr.trim().to_lowercase();
+    let season_param = season_param(year_range, season);
+
+    let game_id = match game_or_week {
+        GameOrWeek::Game(game_id) => game_id,
+        GameOrWeek::Week(week) => client
+            .stats_provider()
+            .fetch_schedule(&season_param)
+            .await?
+            .into_iter()
+            .find(|game| game.week == week && game.involves(&team))
+            .map(|game| game.game_id)
+            .ok_or(StatbookError::NoScheduledGame {
+                team: team_abbr.to_string(),
+                week,
+            })?,
+    };
+
+    client
+        .stats_provider()
+        .fetch_depth_chart(&team, game_id, &season_param)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_mock_client;
+
+    #[tokio::test]
+    async fn test_get_team_stats_mock() {
+        let client = create_mock_client();
+        let stats = get_team_stats(&client, "BUF", Some((2024, 2025)), &Season::Regular)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.name, "Bills");
+        assert_eq!(stats.record.wins, 13);
+        assert_eq!(stats.point_differential(), 157);
+        assert_eq!(stats.season, "2024-2025-regular");
+
+        let missing = get_team_stats(&client, "XYZ", None, &Season::Regular).await;
+        assert!(matches!(missing, Err(StatbookError::TeamNotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_get_standings_mock() {
+        let client = create_mock_client();
+        let standings = get_standings(&client, Some((2024, 2025)), &Season::Regular)
+            .await
+            .unwrap();
+
+        assert_eq!(standings.season, "2024-2025-regular");
+        let east = standings.division("AFC East");
+        assert_eq!(east.len(), 4);
+        assert_eq!(east[0].abbreviation, "BUF");
+        assert!(east
+            .windows(2)
+            .all(|w| w[0].division_rank < w[1].division_rank));
+
+        let picture = standings.playoff_picture("AFC");
+        assert_eq!(picture.len(), 1);
+        assert_eq!(picture[0].seed, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_get_depth_chart_mock() {
+        let client = create_mock_client();
+
+        let by_week = get_depth_chart(&client, "BUF", GameOrWeek::Week(1), None, &Season::Regular)
+            .await
+            .unwrap();
+        let by_game = get_depth_chart(
+            &client,
+            "buf",
+            GameOrWeek::Game(133401),
+            Some((2024, 2025)),
+            &Season::Regular,
+        )
+        .await
+        .unwrap();
+        assert_eq!(by_week.entries, by_game.entries);
+        assert_eq!(by_week.starter("QB").unwrap().player_id, 7549);
+        assert_eq!(by_week.slot("RB2").unwrap().last_name, "Davis");
+
+        let bye =
+            get_depth_chart(&client, "BUF", GameOrWeek::Week(12), None, &Season::Regular).await;
+        assert!(matches!(
+            bye,
+            Err(StatbookError::NoScheduledGame { week: 12, .. })
+        ));
+    }
+}
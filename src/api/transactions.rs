@@ -0,0 +1,47 @@
+use crate::{client::StatbookClient, error::Result, models::Transaction};
+use chrono::NaiveDate;
+
+pub async fn get_transactions(
+    client: &StatbookClient,
+    date_range: (NaiveDate, NaiveDate),
+    team: Option<&str>,
+) -> Result<Vec<Transaction>> {
+    let (from, to) = date_range;
+    let team = team.map(|team| team.trim().to_lowercase());
+
+    client
+        .stats_provider()
+        .fetch_transactions(from, to, team.as_deref())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TransactionKind;
+    use crate::test_utils::create_mock_client;
+
+    #[tokio::test]
+    async fn test_get_transactions_mock() {
+        let client = create_mock_client();
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+
+        let season = get_transactions(&client, (date(9, 1), date(10, 31)), None)
+            .await
+            .unwrap();
+        assert_eq!(season.len(), 2);
+        assert_eq!(season[0].kind, TransactionKind::InjuredReserve);
+
+        let bills = get_transactions(&client, (date(9, 1), date(10, 31)), Some("BUF"))
+            .await
+            .unwrap();
+        assert_eq!(bills.len(), 1);
+        assert_eq!(bills[0].kind, TransactionKind::Trade);
+        assert_eq!(bills[0].from_team.as_deref(), Some("CLE"));
+
+        let september = get_transactions(&client, (date(9, 1), date(9, 30)), Some("BUF"))
+            .await
+            .unwrap();
+        assert!(september.is_empty());
+    }
+}
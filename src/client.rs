@@ -1,9 +1,11 @@
 use crate::{
-    config::StatbookConfig,
+    config::{NewsConfig, StatbookConfig},
     error::Result,
+    models::{NewsQuery, PlayerIndex},
     providers::{MySportsStatsProvider, NewsApiProvider, NewsProvider, StatsProvider},
 };
 use std::sync::Arc;
+use tokio::sync::OnceCell;
 
 /// The main client for interacting with sports statistics and news APIs.
 ///
@@ -13,6 +15,8 @@ use std::sync::Arc;
 pub struct StatbookClient {
     stats_provider: Arc<dyn StatsProvider>,
     news_provider: Arc<dyn NewsProvider>,
+    news_config: NewsConfig,
+    player_index: OnceCell<Arc<PlayerIndex>>,
 }
 
 impl StatbookClient {
@@ -25,20 +29,19 @@ impl StatbookClient {
     ///
     /// * `config` - Configuration containing API keys and endpoint URLs
     pub fn new(config: StatbookConfig) -> Self {
+        let news_config = config.news_config.clone();
         let stats_provider = Arc::new(MySportsStatsProvider::new(config.clone()));
         let news_provider = Arc::new(NewsApiProvider::new(config));
 
-        Self {
-            stats_provider,
-            news_provider,
-        }
+        Self::with_providers(stats_provider, news_provider).with_news_config(news_config)
     }
 
     /// Creates a client with custom providers.
     ///
     /// This constructor allows you to inject custom implementations of the
     /// `StatsProvider` and `NewsProvider` traits, useful for testing or
-    /// when using alternative data sources.
+    /// when using alternative data sources. The client starts with the
+    /// default `NewsConfig`; use `with_news_config()` to change it.
     ///
     /// # Arguments
     ///
@@ -51,9 +54,21 @@ impl StatbookClient {
         Self {
             stats_provider,
             news_provider,
+            news_config: NewsConfig::default(),
+            player_index: OnceCell::new(),
         }
     }
 
+    /// Returns the client with the given news settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `news_config` - Settings used to build default news queries
+    pub fn with_news_config(mut self, news_config: NewsConfig) -> Self {
+        self.news_config = news_config;
+        self
+    }
+
     /// Creates a client using configuration from environment variables.
     ///
     /// This method reads API keys and configuration from the following
@@ -110,6 +125,34 @@ impl StatbookClient {
     pub fn news_provider(&self) -> &Arc<dyn NewsProvider> {
         &self.news_provider
     }
+
+    /// Returns the news settings used to build default news queries.
+    pub fn news_config(&self) -> &NewsConfig {
+        &self.news_config
+    }
+
+    /// Creates a news query for a player from this client's `NewsConfig`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The player name to search for
+    pub fn news_query(&self, name: &str) -> NewsQuery {
+        NewsQuery::from_config(name, &self.news_config)
+    }
+
+    /// Returns the active-player search index, fetching it on first use.
+    ///
+    /// The index is built once per client from `StatsProvider::fetch_players`
+    /// and shared by later searches. Failed fetches are not cached.
+    pub(crate) async fn player_index(&self) -> Result<Arc<PlayerIndex>> {
+        self.player_index
+            .get_or_try_init(|| async {
+                let players = self.stats_provider.fetch_players().await?;
+                Ok(Arc::new(PlayerIndex::new(players)))
+            })
+            .await
+            .cloned()
+    }
 }
 
 #[cfg(test)]
@@ -140,6 +183,24 @@ mod tests {
         assert!(!news.is_empty());
     }
 
+    #[test]
+    fn test_client_news_query_uses_config() {
+        let config = StatbookConfig::builder()
+            .stats_api_key("test-stats-key")
+            .news_api_key("test-news-key")
+            .news_config(NewsConfig::new().with_max_articles(12))
+            .build()
+            .unwrap();
+
+        let client = StatbookClient::new(config);
+        let query = client.news_query("josh-allen");
+        assert_eq!(query.page_size, 12);
+        assert_eq!(query.language, "en");
+
+        let client = create_mock_client().with_news_config(NewsConfig::new().with_max_articles(3));
+        assert_eq!(client.news_query("josh-allen").page_size, 3);
+    }
+
     #[tokio::test]
     async fn test_client_builder() {
         // Test successful config creation
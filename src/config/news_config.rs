@@ -1,3 +1,4 @@
+use chrono::{Days, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,9 +7,20 @@ pub struct NewsConfig {
     pub days_back: u32,
     pub sort_by: SortBy,
     pub language: String,
+    /// Whether the NewsAPI plan supports date filtering; `days_back` is only
+    /// applied when this is set, since the free tier rejects the `from` parameter
+    #[serde(default)]
+    pub paid_tier: bool,
+    /// Most pages `api::news::news_stream` requests before stopping
+    #[serde(default = "default_max_pages")]
+    pub max_pages: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+fn default_max_pages() -> u32 {
+    5
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SortBy {
     #[serde(rename = "publishedAt")]
     PublishedAt,
@@ -25,6 +37,8 @@ impl Default for NewsConfig {
             days_back: 7,
             sort_by: SortBy::PublishedAt,
             language: "en".to_string(),
+            paid_tier: false,
+            max_pages: default_max_pages(),
         }
     }
 }
@@ -53,6 +67,26 @@ impl NewsConfig {
         self.language = language;
         self
     }
+
+    pub fn with_paid_tier(mut self, paid_tier: bool) -> Self {
+        self.paid_tier = paid_tier;
+        self
+    }
+
+    pub fn with_max_pages(mut self, max_pages: u32) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    /// Earliest publication date to search from, `days_back` days before `today`.
+    ///
+    /// Returns `None` on the free tier, where date filtering is unavailable.
+    pub fn from_date(&self, today: NaiveDate) -> Option<NaiveDate> {
+        if !self.paid_tier {
+            return None;
+        }
+        today.checked_sub_days(Days::new(self.days_back.into()))
+    }
 }
 
 impl std::fmt::Display for SortBy {
@@ -64,3 +98,21 @@ impl std::fmt::Display for SortBy {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_date_requires_paid_tier() {
+        let today = NaiveDate::from_ymd_opt(2024, 10, 3).unwrap();
+        let config = NewsConfig::new().with_days_back(7);
+        assert_eq!(config.from_date(today), None);
+
+        let config = config.with_paid_tier(true);
+        assert_eq!(
+            config.from_date(today),
+            NaiveDate::from_ymd_opt(2024, 9, 26)
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::models::PlayerCandidate;
+
 /// Errors that can occur when using the Statbook library.
 ///
 /// This enum represents all possible error conditions that can arise
@@ -25,6 +27,51 @@ pub enum StatbookError {
         name: String,
     },
 
+    /// The requested team was not found.
+    ///
+    /// This error occurs when the team abbreviation doesn't match any team
+    /// in the statistics provider's data for the requested season.
+    #[error("Team '{team}' not found")]
+    TeamNotFound {
+        /// The team abbreviation that was not found
+        team: String,
+    },
+
+    /// The requested game was not found.
+    ///
+    /// This error occurs when no game with the given ID exists in the
+    /// statistics provider's data.
+    #[error("Game {game_id} not found")]
+    GameNotFound {
+        /// The game ID that was not found
+        game_id: u64,
+    },
+
+    /// The team has no game in the requested week.
+    ///
+    /// This error occurs when looking up a game by week for a team that
+    /// is on a bye or whose schedule has not been published.
+    #[error("No game scheduled for '{team}' in week {week}")]
+    NoScheduledGame {
+        /// The team abbreviation that was searched for
+        team: String,
+        /// The week that was searched
+        week: u32,
+    },
+
+    /// More than one player matched the requested name.
+    ///
+    /// This error occurs when several players share a name (e.g., "Josh Allen")
+    /// and no disambiguation hint narrowed the matches down to one. Retry with
+    /// a `PlayerHint` or look the player up by ID.
+    #[error("Player '{name}' is ambiguous: {} players match", candidates.len())]
+    AmbiguousPlayer {
+        /// The name that was searched for
+        name: String,
+        /// Every player that matched the name
+        candidates: Vec<PlayerCandidate>,
+    },
+
     /// A network-related error occurred.
     ///
     /// This error wraps HTTP client errors, including connection timeouts,
@@ -63,6 +110,17 @@ pub enum StatbookError {
         message: String,
     },
 
+    /// The news API will not return any more results for the query.
+    ///
+    /// This error occurs when paging past the maximum number of results the
+    /// news provider serves on the account's plan, such as NewsAPI's
+    /// `maximumResultsReached` error on the developer tier.
+    #[error("News API result limit reached: {message}")]
+    NewsResultLimit {
+        /// Error message from the API response
+        message: String,
+    },
+
     /// A configuration error occurred.
     ///
     /// This error occurs when there are issues with the client configuration,
@@ -70,6 +128,17 @@ pub enum StatbookError {
     #[error("Configuration error: {0}")]
     Config(String),
 
+    /// The provider does not support the requested operation.
+    ///
+    /// This error is returned by the default implementations of optional
+    /// provider methods, so custom providers only need to implement the
+    /// data they can actually serve.
+    #[error("Operation '{operation}' is not supported by this provider")]
+    Unsupported {
+        /// The provider method that was called (e.g., "fetch_player_profile")
+        operation: String,
+    },
+
     /// A validation error occurred.
     ///
     /// This error occurs when input validation fails, such as when
@@ -124,7 +124,16 @@ mod utils;
 pub use client::StatbookClient;
 pub use config::{NewsConfig, SortBy, StatbookConfig};
 pub use error::{Result, StatbookError};
-pub use models::{Article, NewsQuery, PlayerNews, PlayerStats, PlayerSummary, Season};
+pub use models::{
+    Article, Boxscore, BoxscorePlayer, DefensiveLine, DepthChart, DepthChartEntry, FieldPosition,
+    Game, GameLine, GameLog, GameOrWeek, GameStatus, HomeAway, InjuredPlayer, Injury, InjuryStatus,
+    Moneyline, NewsQuery, PassingLine, Play, PlayParticipant, PlayRole, PlayType, PlayerCandidate,
+    PlayerDirectory, PlayerHint, PlayerMatch, PlayerNews, PlayerProfile, PlayerProp, PlayerStats,
+    PlayerSummary, PracticeParticipation, QuarterScore, ReceivingLine, Record, ReturnLine,
+    RushingLine, ScheduleFilter, Score, SearchIn, Season, SnapUsage, Spread, Standings, Streak,
+    TeamBoxscore, TeamDefense, TeamOffense, TeamStanding, TeamStats, Total, Transaction,
+    TransactionKind, TwoPointLine,
+};
 pub use providers::{MockNewsProvider, MockStatsProvider, NewsProvider, StatsProvider};
 
 // Re-export test utilities directly
@@ -0,0 +1,282 @@
+use crate::models::{
+    game::Game,
+    parsers::game_parser::{BoxscorePlayerEntry, BoxscoreResponse, BoxscoreTeam, QuarterScoring},
+    stats::{DefensiveLine, PassingLine, ReceivingLine, ReturnLine, RushingLine, TwoPointLine},
+    team::{TeamDefense, TeamOffense},
+};
+
+/// Points scored by each team in a single quarter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QuarterScore {
+    /// Quarter number (5 and up for overtime periods)
+    pub quarter: u32,
+    /// Home team points in the quarter
+    pub home: u32,
+    /// Away team points in the quarter
+    pub away: u32,
+}
+
+impl QuarterScore {
+    pub(crate) fn from_parsed(quarter: &QuarterScoring) -> Self {
+        Self {
+            quarter: quarter.quarter_number.unwrap_or(0),
+            home: quarter.home_score.unwrap_or(0),
+            away: quarter.away_score.unwrap_or(0),
+        }
+    }
+}
+
+/// A player's stat line in a boxscore.
+///
+/// Stat lines follow the same rules as `PlayerStats`: each is `None`
+/// when the player recorded nothing in that category during the game.
+
+#[derive(Debug, Clone, Default)]
+pub struct BoxscorePlayer {
+    /// Provider's numeric player ID
+    pub player_id: u64,
+    /// Player's first name
+    pub first_name: String,
+    /// Player's last name
+    pub last_name: String,
+    /// Position played in the game (e.g., "QB")
+    pub position: String,
+    /// Jersey number
+    pub jersey_number: u32,
+    /// Passing line for the game
+    pub passing: Option<PassingLine>,
+    /// Rushing line for the game
+    pub rushing: Option<RushingLine>,
+    /// Receiving line for the game
+    pub receiving: Option<ReceivingLine>,
+    /// Defensive line for the game
+    pub defense: Option<DefensiveLine>,
+    /// Kickoff returns for the game
+    pub kick_returns: Option<ReturnLine>,
+    /// Punt returns for the game
+    pub punt_returns: Option<ReturnLine>,
+    /// Two-point tries for the game
+    pub two_point: Option<TwoPointLine>,
+}
+
+impl BoxscorePlayer {
+    pub(crate) fn from_parsed(entry: &BoxscorePlayerEntry) -> Self {
+        let info = &entry.player_info;
+        let statistics = entry.player_stats.first();
+
+        Self {
+            player_id: info.id.unwrap_or(0),
+            first_name: info.first_name.clone().unwrap_or_default(),
+            last_name: info.last_name.clone().unwrap_or_default(),
+            position: info
+                .position
+                .clone()
+                .or_else(|| info.primary_position.clone())
+                .unwrap_or_default(),
+            jersey_number: info.jersey_number.unwrap_or(0),
+            passing: PassingLine::from_parsed(statistics.and_then(|s| s.passing_stats.as_ref())),
+            rushing: RushingLine::from_parsed(statistics.and_then(|s| s.rushing_stats.as_ref())),
+            receiving: ReceivingLine::from_parsed(
+                statistics.and_then(|s| s.receiving_stats.as_ref()),
+            ),
+            defense: DefensiveLine::from_parsed(
+                statistics.and_then(|s| s.tackles_stats.as_ref()),
+                statistics.and_then(|s| s.interceptions_stats.as_ref()),
+                statistics.and_then(|s| s.fumbles_stats.as_ref()),
+            ),
+            kick_returns: ReturnLine::from_kickoffs(
+                statistics.and_then(|s| s.kick_off_returns.as_ref()),
+            ),
+            punt_returns: ReturnLine::from_punts(statistics.and_then(|s| s.punt_returns.as_ref())),
+            two_point: TwoPointLine::from_parsed(
+                statistics.and_then(|s| s.two_point_attempts.as_ref()),
+            ),
+        }
+    }
+}
+
+/// One team's side of a boxscore.
+
+#[derive(Debug, Clone, Default)]
+pub struct TeamBoxscore {
+    /// Team abbreviation (e.g., "BUF")
+    pub team: String,
+    /// Points scored
+    pub points: u32,
+    /// Offensive totals for the game
+    pub offense: TeamOffense,
+    /// Defensive totals for the game
+    pub defense: TeamDefense,
+    /// Stat lines for every player who recorded a stat
+    pub players: Vec<BoxscorePlayer>,
+}
+
+impl TeamBoxscore {
+    /// Finds a player's line by the provider's player ID.
+    pub fn player_by_id(&self, player_id: u64) -> Option<&BoxscorePlayer> {
+        self.players
+            .iter()
+            .find(|player| player.player_id == player_id)
+    }
+
+    /// Returns every player's line with this last name, case-insensitively.
+    ///
+    /// Rosters often carry more than one player with the same last name, so
+    /// prefer `player_by_id` when the ID is known.
+    pub fn players_named(&self, last_name: &str) -> Vec<&BoxscorePlayer> {
+        self.players
+            .iter()
+            .filter(|player| player.last_name.eq_ignore_ascii_case(last_name))
+            .collect()
+    }
+
+    pub(crate) fn from_parsed(team: String, points: u32, parsed: &BoxscoreTeam) -> Self {
+        let (offense, defense) = match parsed.team_stats.first() {
+            Some(statistics) => (
+                TeamOffense::from_parsed(statistics),
+                TeamDefense::from_parsed(statistics),
+            ),
+            None => Default::default(),
+        };
+
+        Self {
+            team,
+            points,
+            offense,
+            defense,
+            players: parsed
+                .players
+                .iter()
+                .map(BoxscorePlayer::from_parsed)
+                .collect(),
+        }
+    }
+}
+
+/// Full boxscore for a single game.
+///
+/// Quarters are empty and totals are zero for games that have not started.
+
+#[derive(Debug, Clone)]
+pub struct Boxscore {
+    /// Schedule details, status and final or current score
+    pub game: Game,
+    /// Scoring by quarter, in order
+    pub quarters: Vec<QuarterScore>,
+    /// Home team totals and player lines
+    pub home: TeamBoxscore,
+    /// Away team totals and player lines
+    pub away: TeamBoxscore,
+}
+
+impl Boxscore {
+    pub(crate) fn from_parsed(response: &BoxscoreResponse) -> Self {
+        let game = Game::from_parsed(&response.game, response.scoring.as_ref());
+        let score = game.score.unwrap_or_default();
+        let stats = response.stats.as_ref();
+        let empty = BoxscoreTeam::default();
+
+        Self {
+            quarters: response
+                .scoring
+                .as_ref()
+                .map(|scoring| {
+                    scoring
+                        .quarters
+                        .iter()
+                        .map(QuarterScore::from_parsed)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            home: TeamBoxscore::from_parsed(
+                game.home_team.clone(),
+                score.home,
+                stats.map_or(&empty, |s| &s.home),
+            ),
+            away: TeamBoxscore::from_parsed(
+                game.away_team.clone(),
+                score.away,
+                stats.map_or(&empty, |s| &s.away),
+            ),
+            game,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GameStatus;
+
+    #[test]
+    fn test_boxscore_from_parsed() {
+        let json = r#"{
+            "game": {
+                "id": 133421,
+                "week": 1,
+                "startTime": "2024-09-08T17:00:00.000Z",
+                "awayTeam": { "abbreviation": "ARI" },
+                "homeTeam": { "abbreviation": "BUF" },
+                "playedStatus": "COMPLETED"
+            },
+            "scoring": {
+                "awayScoreTotal": 28,
+                "homeScoreTotal": 34,
+                "quarters": [
+                    { "quarterNumber": 1, "awayScore": 14, "homeScore": 0 },
+                    { "quarterNumber": 2, "awayScore": 3, "homeScore": 17 },
+                    { "quarterNumber": 3, "awayScore": 3, "homeScore": 3 },
+                    { "quarterNumber": 4, "awayScore": 8, "homeScore": 14 }
+                ]
+            },
+            "stats": {
+                "away": {
+                    "teamStats": [{ "rushing": { "rushAttempts": 24, "rushYards": 127 } }],
+                    "players": []
+                },
+                "home": {
+                    "teamStats": [{
+                        "passing": { "passAttempts": 23, "passYards": 232, "passNetYards": 224 },
+                        "tackles": { "sacks": 2.0 }
+                    }],
+                    "players": [{
+                        "player": { "id": 7549, "firstName": "Josh", "lastName": "Allen", "position": "QB", "jerseyNumber": 17 },
+                        "playerStats": [{
+                            "passing": { "passAttempts": 23, "passCompletions": 18, "passYards": 232, "passTD": 2 },
+                            "rushing": { "rushAttempts": 9, "rushYards": 39, "rushTD": 2 }
+                        }]
+                    }, {
+                        "player": { "id": 8550, "firstName": "Kyle", "lastName": "Allen", "position": "QB", "jerseyNumber": 9 },
+                        "playerStats": [{
+                            "rushing": { "rushAttempts": 1, "rushYards": -1 }
+                        }]
+                    }]
+                }
+            }
+        }"#;
+
+        let response: BoxscoreResponse = serde_json::from_str(json).unwrap();
+        let boxscore = Boxscore::from_parsed(&response);
+
+        assert_eq!(boxscore.game.status, GameStatus::Final);
+        assert_eq!(boxscore.quarters.len(), 4);
+        assert_eq!(
+            boxscore.quarters.iter().map(|q| q.home).sum::<u32>(),
+            boxscore.home.points
+        );
+        assert_eq!(boxscore.home.team, "BUF");
+        assert_eq!(boxscore.home.offense.passing_yards, 224);
+        assert_eq!(boxscore.home.defense.sacks, 2.0);
+        assert_eq!(boxscore.away.points, 28);
+        assert_eq!(boxscore.away.offense.rushing_yards, 127);
+
+        assert_eq!(boxscore.home.players_named("allen").len(), 2);
+        assert!(boxscore.home.player_by_id(1).is_none());
+
+        let allen = boxscore.home.player_by_id(7549).unwrap();
+        assert_eq!(allen.position, "QB");
+        assert_eq!(allen.passing.as_ref().unwrap().touchdowns, 2);
+        assert_eq!(allen.rushing.as_ref().unwrap().touchdowns, 2);
+        assert!(allen.receiving.is_none());
+    }
+}
@@ -0,0 +1,176 @@
+use crate::models::parsers::game_parser::{LineupPosition, TeamLineup};
+
+/// Selects which game a depth chart is fetched for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOrWeek {
+    /// A specific game by the provider's game ID
+    Game(u64),
+    /// The team's game in the given week of the requested season
+    Week(u32),
+}
+
+/// A single slot on a depth chart, such as QB1 or RB2.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthChartEntry {
+    /// Unit the slot belongs to (e.g., "Offense", "Defense", "SpecialTeams")
+    pub side: String,
+    /// Position abbreviation (e.g., "QB")
+    pub position: String,
+    /// Depth at the position, starting at 1 for the starter
+    pub depth: u32,
+    /// Provider's numeric player ID
+    pub player_id: u64,
+    /// Player's first name
+    pub first_name: String,
+    /// Player's last name
+    pub last_name: String,
+    /// Jersey number
+    pub jersey_number: u32,
+}
+
+impl DepthChartEntry {
+    /// Slot label combining position and depth (e.g., "QB1").
+    pub fn slot(&self) -> String {
+        format!("{}{}", self.position, self.depth)
+    }
+
+    /// Parses a lineup slot such as "Offense-QB-1"; empty slots return `None`.
+    pub(crate) fn from_parsed(lineup_position: &LineupPosition) -> Option<Self> {
+        let player = lineup_position.player.as_ref()?;
+        let mut parts = lineup_position.position.as_deref()?.split('-');
+        let side = parts.next()?;
+        let position = parts.next()?;
+        let depth = parts.next()?.parse().ok()?;
+
+        Some(Self {
+            side: side.to_string(),
+            position: position.to_string(),
+            depth,
+            player_id: player.id.unwrap_or(0),
+            first_name: player.first_name.clone().unwrap_or_default(),
+            last_name: player.last_name.clone().unwrap_or_default(),
+            jersey_number: player.jersey_number.unwrap_or(0),
+        })
+    }
+}
+
+/// A team's depth chart for a single game.
+///
+/// Entries are grouped by position in the order the provider lists them
+/// and ordered by depth within each position.
+
+#[derive(Debug, Clone, Default)]
+pub struct DepthChart {
+    /// Provider's numeric game ID
+    pub game_id: u64,
+    /// Team abbreviation (e.g., "BUF")
+    pub team: String,
+    /// Whether this is the confirmed game-day lineup rather than the expected one
+    pub confirmed: bool,
+    /// Filled depth chart slots
+    pub entries: Vec<DepthChartEntry>,
+}
+
+impl DepthChart {
+    /// Returns every player at a position, starter first.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Position abbreviation (e.g., "WR"), compared case-insensitively
+    pub fn position(&self, position: &str) -> Vec<&DepthChartEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.position.eq_ignore_ascii_case(position))
+            .collect()
+    }
+
+    /// Returns the starter at a position.
+    pub fn starter(&self, position: &str) -> Option<&DepthChartEntry> {
+        self.position(position).into_iter().next()
+    }
+
+    /// Returns the player in a slot such as "QB1" or "RB2".
+    pub fn slot(&self, slot: &str) -> Option<&DepthChartEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.slot().eq_ignore_ascii_case(slot))
+    }
+
+    /// Builds a depth chart from the confirmed lineup if published,
+    /// otherwise from the expected one.
+    pub(crate) fn from_parsed(game_id: u64, lineup: &TeamLineup) -> Self {
+        let (confirmed, positions) = match (&lineup.actual, &lineup.expected) {
+            (Some(actual), _) if !actual.lineup_positions.is_empty() => {
+                (true, actual.lineup_positions.as_slice())
+            }
+            (_, Some(expected)) => (false, expected.lineup_positions.as_slice()),
+            _ => (false, [].as_slice()),
+        };
+
+        let mut entries: Vec<DepthChartEntry> = positions
+            .iter()
+            .filter_map(DepthChartEntry::from_parsed)
+            .collect();
+
+        let mut groups: Vec<(String, String)> = Vec::new();
+        for entry in &entries {
+            let group = (entry.side.clone(), entry.position.clone());
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+        entries.sort_by_key(|entry| {
+            let group = groups
+                .iter()
+                .position(|(side, position)| *side == entry.side && *position == entry.position);
+            (group, entry.depth)
+        });
+
+        Self {
+            game_id,
+            team: lineup.team.abbreviation.clone().unwrap_or_default(),
+            confirmed,
+            entries,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parsers::game_parser::LineupResponse;
+
+    #[test]
+    fn test_depth_chart_from_parsed() {
+        let json = r#"{
+            "game": { "id": 133421 },
+            "teamLineups": [{
+                "team": { "id": 48, "abbreviation": "BUF" },
+                "expected": {
+                    "lineupPositions": [
+                        { "position": "Offense-QB-1", "player": { "id": 7549, "firstName": "Josh", "lastName": "Allen", "jerseyNumber": 17 } },
+                        { "position": "Offense-RB-2", "player": { "id": 21001, "firstName": "Ray", "lastName": "Davis" } },
+                        { "position": "Offense-RB-1", "player": { "id": 18110, "firstName": "James", "lastName": "Cook" } },
+                        { "position": "Offense-WR-3", "player": null },
+                        { "position": "Defense-LB-1", "player": { "id": 17005, "firstName": "Matt", "lastName": "Milano" } }
+                    ]
+                },
+                "actual": null
+            }]
+        }"#;
+
+        let response: LineupResponse = serde_json::from_str(json).unwrap();
+        let chart = DepthChart::from_parsed(133421, &response.team_lineups[0]);
+
+        assert_eq!(chart.team, "BUF");
+        assert!(!chart.confirmed);
+        assert_eq!(chart.entries.len(), 4);
+        assert_eq!(chart.starter("qb").unwrap().player_id, 7549);
+
+        let backs = chart.position("RB");
+        assert_eq!(backs[0].last_name, "Cook");
+        assert_eq!(backs[1].slot(), "RB2");
+        assert_eq!(chart.slot("LB1").unwrap().side, "Defense");
+        assert!(chart.slot("WR3").is_none());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::models::player::PlayerProfile;
+
+/// A filterable list of player profiles.
+///
+/// Filters consume the directory and return a narrowed one, so they can
+/// be chained. All string comparisons are case-insensitive.
+
+#[derive(Debug, Clone, Default)]
+pub struct PlayerDirectory {
+    /// Players currently in the directory
+    pub players: Vec<PlayerProfile>,
+}
+
+impl PlayerDirectory {
+    /// Creates a directory from a list of player profiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `players` - Profiles to include
+    pub fn new(players: Vec<PlayerProfile>) -> Self {
+        Self { players }
+    }
+
+    /// Keeps only players currently on the given team.
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - Team abbreviation (e.g., "BUF")
+    pub fn by_team(self, team: &str) -> Self {
+        self.filter(|player| player.current_team.eq_ignore_ascii_case(team))
+    }
+
+    /// Keeps only players at the given primary position.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Position abbreviation (e.g., "WR")
+    pub fn by_position(self, position: &str) -> Self {
+        self.filter(|player| player.primary_position.eq_ignore_ascii_case(position))
+    }
+
+    /// Keeps only players with the given roster status.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - Provider roster status (e.g., "ROSTER", "RETIRED")
+    pub fn by_roster_status(self, status: &str) -> Self {
+        self.filter(|player| player.roster_status.eq_ignore_ascii_case(status))
+    }
+
+    /// Keeps only rookies.
+    pub fn rookies(self) -> Self {
+        self.filter(|player| player.rookie)
+    }
+
+    /// Keeps only players who have not retired, including free agents.
+    pub fn active(self) -> Self {
+        self.filter(PlayerProfile::is_active)
+    }
+
+    /// Returns the number of players in the directory.
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    /// Returns true if the directory contains no players.
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    /// Returns an iterator over the players in the directory.
+    pub fn iter(&self) -> std::slice::Iter<'_, PlayerProfile> {
+        self.players.iter()
+    }
+
+    fn filter(mut self, predicate: impl Fn(&PlayerProfile) -> bool) -> Self {
+        self.players.retain(|player| predicate(player));
+        self
+    }
+}
+
+impl IntoIterator for PlayerDirectory {
+    type Item = PlayerProfile;
+    type IntoIter = std::vec::IntoIter<PlayerProfile>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.players.into_iter()
+    }
+}
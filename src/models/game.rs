@@ -0,0 +1,315 @@
+use crate::{
+    models::parsers::game_parser::{GameSchedule, GameScore, TeamReference},
+    utils::parse_timestamp,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::US::Eastern;
+
+/// Where a game stands on the schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameStatus {
+    /// Not started yet
+    Scheduled,
+    /// Currently being played
+    InProgress,
+    /// Completed
+    Final,
+    /// Postponed or canceled
+    Postponed,
+}
+
+impl GameStatus {
+    pub(crate) fn from_parsed(schedule_status: Option<&str>, played_status: Option<&str>) -> Self {
+        if matches!(schedule_status, Some("POSTPONED" | "CANCELED")) {
+            return GameStatus::Postponed;
+        }
+
+        match played_status {
+            Some("LIVE") => GameStatus::InProgress,
+            Some("COMPLETED" | "COMPLETED_PENDING_REVIEW") => GameStatus::Final,
+            _ => GameStatus::Scheduled,
+        }
+    }
+}
+
+/// Points scored by each team in a game.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Score {
+    /// Home team points
+    pub home: u32,
+    /// Away team points
+    pub away: u32,
+}
+
+/// A scheduled, live or completed game.
+///
+/// `score` is `None` until the game has started.
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    /// Provider's numeric game ID
+    pub game_id: u64,
+    /// Week of the season the game is played in
+    pub week: u32,
+    /// Scheduled kickoff time, or `None` if the provider's timestamp is missing or unparsable
+    pub start_time: Option<DateTime<Utc>>,
+    /// Home team abbreviation
+    pub home_team: String,
+    /// Away team abbreviation
+    pub away_team: String,
+    /// Stadium name, if known
+    pub venue: Option<String>,
+    /// Current status of the game
+    pub status: GameStatus,
+    /// Current or final score
+    pub score: Option<Score>,
+}
+
+impl Game {
+    /// Returns true if the given team is playing in this game.
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - Team abbreviation (e.g., "BUF"), compared case-insensitively
+    pub fn involves(&self, team: &str) -> bool {
+        self.home_team.eq_ignore_ascii_case(team) || self.away_team.eq_ignore_ascii_case(team)
+    }
+
+    /// Returns the abbreviation of the winning team once the game is final.
+    ///
+    /// Returns `None` for unfinished games and ties.
+    pub fn winner(&self) -> Option<&str> {
+        let score = self.score.filter(|_| self.status == GameStatus::Final)?;
+        match score.home.cmp(&score.away) {
+            std::cmp::Ordering::Greater => Some(&self.home_team),
+            std::cmp::Ordering::Less => Some(&self.away_team),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    pub(crate) fn from_parsed(schedule: &GameSchedule, score: Option<&GameScore>) -> Self {
+        let status = GameStatus::from_parsed(
+            schedule.schedule_status.as_deref(),
+            schedule.played_status.as_deref(),
+        );
+        let abbreviation = |team: Option<&TeamReference>| {
+            team.and_then(|t| t.abbreviation.clone())
+                .unwrap_or_default()
+        };
+
+        let score = match status {
+            GameStatus::InProgress | GameStatus::Final => score.map(|score| Score {
+                home: score.home_score_total.unwrap_or(0),
+                away: score.away_score_total.unwrap_or(0),
+            }),
+            GameStatus::Scheduled | GameStatus::Postponed => None,
+        };
+
+        Self {
+            game_id: schedule.id.unwrap_or(0),
+            week: schedule.week.unwrap_or(0),
+            start_time: schedule.start_time.as_deref().and_then(parse_timestamp),
+            home_team: abbreviation(schedule.home_team.as_ref()),
+            away_team: abbreviation(schedule.away_team.as_ref()),
+            venue: schedule.venue.as_ref().and_then(|venue| venue.name.clone()),
+            status,
+            score,
+        }
+    }
+}
+
+/// Narrows a schedule to matching games.
+///
+/// An empty filter matches every game. Dates are compared against the
+/// kickoff date in US Eastern time, the league's schedule timezone, so
+/// night games stay on the day they are billed for. Both ends of the range
+/// are inclusive; games without a kickoff time never match a date range.
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleFilter {
+    /// Only games in this week
+    pub week: Option<u32>,
+    /// Only games involving this team abbreviation
+    pub team: Option<String>,
+    /// Only games kicking off between these dates (US Eastern)
+    pub date_range: Option<(NaiveDate, NaiveDate)>,
+}
+
+impl ScheduleFilter {
+    /// Creates a filter that matches every game.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the filter to a single week.
+    pub fn with_week(mut self, week: u32) -> Self {
+        self.week = Some(week);
+        self
+    }
+
+    /// Restricts the filter to games involving a team.
+    pub fn with_team(mut self, team: &str) -> Self {
+        self.team = Some(team.to_string());
+        self
+    }
+
+    /// Restricts the filter to games kicking off between two dates.
+    ///
+    /// Kickoffs are converted to US Eastern time before comparing, so a
+    /// Sunday night game at 00:20 UTC Monday matches a Sunday range.
+    pub fn with_date_range(mut self, from: NaiveDate, to: NaiveDate) -> Self {
+        self.date_range = Some((from, to));
+        self
+    }
+
+    /// Returns true if the game satisfies every criterion in the filter.
+    pub fn matches(&self, game: &Game) -> bool {
+        let week_matches = match self.week {
+            Some(week) => game.week == week,
+            None => true,
+        };
+        let team_matches = match &self.team {
+            Some(team) => game.involves(team),
+            None => true,
+        };
+        let date_matches = match self.date_range {
+            Some((from, to)) => game.start_time.is_some_and(|start_time| {
+                (from..=to).contains(&start_time.with_timezone(&Eastern).date_naive())
+            }),
+            None => true,
+        };
+
+        week_matches && team_matches && date_matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parsers::game_parser::GamesResponse;
+
+    #[test]
+    fn test_game_from_parsed() {
+        let json = r#"{
+            "games": [{
+                "schedule": {
+                    "id": 133421,
+                    "week": 1,
+                    "startTime": "2024-09-08T17:00:00.000Z",
+                    "awayTeam": { "id": 72, "abbreviation": "ARI" },
+                    "homeTeam": { "id": 48, "abbreviation": "BUF" },
+                    "venue": { "id": 1, "name": "Highmark Stadium" },
+                    "scheduleStatus": "NORMAL",
+                    "playedStatus": "COMPLETED"
+                },
+                "score": { "awayScoreTotal": 28, "homeScoreTotal": 34 }
+            }, {
+                "schedule": {
+                    "id": 133500,
+                    "week": 9,
+                    "startTime": "2024-11-03T18:00:00.000Z",
+                    "awayTeam": { "abbreviation": "MIA" },
+                    "homeTeam": { "abbreviation": "BUF" },
+                    "playedStatus": "UNPLAYED"
+                },
+                "score": { "awayScoreTotal": null, "homeScoreTotal": null }
+            }]
+        }"#;
+
+        let response: GamesResponse = serde_json::from_str(json).unwrap();
+        let played = Game::from_parsed(
+            &response.games[0].schedule,
+            response.games[0].score.as_ref(),
+        );
+        let upcoming = Game::from_parsed(
+            &response.games[1].schedule,
+            response.games[1].score.as_ref(),
+        );
+
+        assert_eq!(played.game_id, 133421);
+        assert_eq!(played.venue.as_deref(), Some("Highmark Stadium"));
+        assert_eq!(played.status, GameStatus::Final);
+        assert_eq!(played.score, Some(Score { home: 34, away: 28 }));
+        assert_eq!(played.winner(), Some("BUF"));
+
+        assert_eq!(upcoming.status, GameStatus::Scheduled);
+        assert!(upcoming.score.is_none());
+        assert!(upcoming.winner().is_none());
+    }
+
+    #[test]
+    fn test_schedule_filter() {
+        let game = Game {
+            game_id: 1,
+            week: 2,
+            start_time: "2024-09-13T00:15:00Z".parse().ok(),
+            home_team: "MIA".to_string(),
+            away_team: "BUF".to_string(),
+            venue: None,
+            status: GameStatus::Final,
+            score: None,
+        };
+        let date = |day| NaiveDate::from_ymd_opt(2024, 9, day).unwrap();
+
+        assert!(ScheduleFilter::new().matches(&game));
+        assert!(ScheduleFilter::new()
+            .with_week(2)
+            .with_team("buf")
+            .matches(&game));
+        assert!(!ScheduleFilter::new().with_team("NYJ").matches(&game));
+        // Thursday night kickoff: 00:15 UTC on the 13th is the 12th in Eastern time
+        assert!(ScheduleFilter::new()
+            .with_date_range(date(12), date(12))
+            .matches(&game));
+        assert!(!ScheduleFilter::new()
+            .with_date_range(date(13), date(13))
+            .matches(&game));
+        assert!(!ScheduleFilter::new()
+            .with_date_range(date(1), date(11))
+            .matches(&game));
+
+        let sunday_night = Game {
+            start_time: "2024-09-09T00:20:00Z".parse().ok(),
+            ..game.clone()
+        };
+        let sunday = date(8);
+        assert!(ScheduleFilter::new()
+            .with_date_range(sunday, sunday)
+            .matches(&sunday_night));
+        assert!(!ScheduleFilter::new()
+            .with_date_range(date(9), date(9))
+            .matches(&sunday_night));
+
+        let unscheduled = Game {
+            start_time: None,
+            ..game
+        };
+        assert!(ScheduleFilter::new().with_week(2).matches(&unscheduled));
+        assert!(!ScheduleFilter::new()
+            .with_date_range(date(1), date(30))
+            .matches(&unscheduled));
+    }
+
+    #[test]
+    fn test_game_without_start_time() {
+        let json = r#"{
+            "games": [{
+                "schedule": {
+                    "id": 133499,
+                    "week": 18,
+                    "startTime": "",
+                    "awayTeam": { "abbreviation": "NE" },
+                    "homeTeam": { "abbreviation": "BUF" },
+                    "scheduleStatus": "NORMAL",
+                    "playedStatus": "UNPLAYED"
+                },
+                "score": {}
+            }]
+        }"#;
+
+        let response: GamesResponse = serde_json::from_str(json).unwrap();
+        let game = Game::from_parsed(&response.games[0].schedule, None);
+
+        assert_eq!(game.week, 18);
+        assert!(game.start_time.is_none());
+    }
+}
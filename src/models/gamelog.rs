@@ -0,0 +1,157 @@
+use crate::{
+    models::{
+        parsers::player_parser::Gamelog,
+        stats::{
+            DefensiveLine, PassingLine, ReceivingLine, ReturnLine, RushingLine, SnapUsage,
+            TwoPointLine,
+        },
+    },
+    utils::parse_timestamp,
+};
+use chrono::{DateTime, Utc};
+
+/// Whether a team played a game at home or on the road.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HomeAway {
+    Home,
+    Away,
+}
+
+/// A player's stat line for a single game.
+///
+/// Stat lines follow the same rules as `PlayerStats`: each is `None`
+/// when the player recorded nothing in that category during the game.
+
+#[derive(Debug, Clone)]
+pub struct GameLog {
+    /// Provider's numeric game ID
+    pub game_id: u64,
+    /// Provider's numeric player ID
+    pub player_id: u64,
+    /// Week of the season the game was played in
+    pub week: u32,
+    /// Scheduled kickoff time, or `None` if the provider's timestamp is missing or unparsable
+    pub start_time: Option<DateTime<Utc>>,
+    /// Abbreviation of the player's team in this game
+    pub team: String,
+    /// Abbreviation of the opposing team
+    pub opponent: String,
+    /// Whether the player's team was home or away
+    pub home_away: HomeAway,
+    /// Passing line for the game
+    pub passing: Option<PassingLine>,
+    /// Rushing line for the game
+    pub rushing: Option<RushingLine>,
+    /// Receiving line for the game
+    pub receiving: Option<ReceivingLine>,
+    /// Defensive line for the game
+    pub defense: Option<DefensiveLine>,
+    /// Kickoff returns for the game
+    pub kick_returns: Option<ReturnLine>,
+    /// Punt returns for the game
+    pub punt_returns: Option<ReturnLine>,
+    /// Two-point tries for the game
+    pub two_point: Option<TwoPointLine>,
+    /// Snap counts for the game
+    pub usage: Option<SnapUsage>,
+}
+
+impl GameLog {
+    pub(crate) fn from_parsed(gamelog: &Gamelog) -> Self {
+        let game = &gamelog.game;
+        let statistics = &gamelog.statistics;
+        let team = gamelog.team_info.abbreviation.clone().unwrap_or_default();
+        let home_team = game.home_team_abbreviation.clone().unwrap_or_default();
+        let away_team = game.away_team_abbreviation.clone().unwrap_or_default();
+
+        let (home_away, opponent) = if team == home_team {
+            (HomeAway::Home, away_team)
+        } else {
+            (HomeAway::Away, home_team)
+        };
+
+        Self {
+            game_id: game.id.unwrap_or(0),
+            player_id: gamelog.player_info.id.unwrap_or(0),
+            week: game.week.unwrap_or(0),
+            start_time: game.start_time.as_deref().and_then(parse_timestamp),
+            team,
+            opponent,
+            home_away,
+            passing: PassingLine::from_parsed(statistics.passing_stats.as_ref()),
+            rushing: RushingLine::from_parsed(statistics.rushing_stats.as_ref()),
+            receiving: ReceivingLine::from_parsed(statistics.receiving_stats.as_ref()),
+            defense: DefensiveLine::from_parsed(
+                statistics.tackles_stats.as_ref(),
+                statistics.interceptions_stats.as_ref(),
+                statistics.fumbles_stats.as_ref(),
+            ),
+            kick_returns: ReturnLine::from_kickoffs(statistics.kick_off_returns.as_ref()),
+            punt_returns: ReturnLine::from_punts(statistics.punt_returns.as_ref()),
+            two_point: TwoPointLine::from_parsed(statistics.two_point_attempts.as_ref()),
+            usage: SnapUsage::from_parsed(
+                statistics.snap_counts.as_ref(),
+                statistics.miscellaneous_stats.as_ref(),
+                statistics.games_played.unwrap_or(0),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parsers::player_parser::GamelogResponse;
+
+    #[test]
+    fn test_gamelog_from_parsed() {
+        let json = r#"{
+            "gamelogs": [{
+                "game": {
+                    "id": 133421,
+                    "week": 1,
+                    "startTime": "2024-09-08T17:00:00.000Z",
+                    "awayTeamAbbreviation": "ARI",
+                    "homeTeamAbbreviation": "BUF"
+                },
+                "player": { "id": 7549, "firstName": "Josh", "lastName": "Allen" },
+                "team": { "id": 48, "abbreviation": "BUF" },
+                "stats": {
+                    "gamesPlayed": 1,
+                    "passing": { "passAttempts": 23, "passYards": 232, "passTD": 2 }
+                }
+            }]
+        }"#;
+
+        let response: GamelogResponse = serde_json::from_str(json).unwrap();
+        let log = GameLog::from_parsed(&response.gamelogs[0]);
+
+        assert_eq!(log.game_id, 133421);
+        assert_eq!(log.opponent, "ARI");
+        assert_eq!(log.home_away, HomeAway::Home);
+        assert_eq!(
+            log.start_time.unwrap().to_rfc3339(),
+            "2024-09-08T17:00:00+00:00"
+        );
+        assert_eq!(log.passing.unwrap().yards, 232);
+        assert!(log.rushing.is_none());
+    }
+
+    #[test]
+    fn test_gamelog_without_start_time() {
+        let json = r#"{
+            "gamelogs": [{
+                "game": { "id": 133422, "week": 2, "startTime": "TBD" },
+                "player": { "id": 7549 },
+                "team": { "abbreviation": "BUF" },
+                "stats": {}
+            }]
+        }"#;
+
+        let response: GamelogResponse = serde_json::from_str(json).unwrap();
+        let log = GameLog::from_parsed(&response.gamelogs[0]);
+
+        assert_eq!(log.week, 2);
+        assert!(log.start_time.is_none());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    models::{parsers::player_parser::PlayerInfo, player::PlayerCandidate},
+    utils::parse_timestamp,
+};
+use chrono::{DateTime, Utc};
+
+/// A player's availability designation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InjuryStatus {
+    Probable,
+    Questionable,
+    Doubtful,
+    Out,
+    /// On injured reserve
+    InjuredReserve,
+    /// On the physically unable to perform list
+    Pup,
+    /// Injured, but the provider reported no designation
+    Unknown,
+    /// Any other designation, as reported by the provider
+    Other(String),
+}
+
+impl InjuryStatus {
+    /// Returns true if the player will not play.
+    pub fn is_out(&self) -> bool {
+        matches!(
+            self,
+            InjuryStatus::Out | InjuryStatus::InjuredReserve | InjuryStatus::Pup
+        )
+    }
+
+    pub(crate) fn from_parsed(
+        playing_probability: Option<&str>,
+        roster_status: Option<&str>,
+    ) -> Self {
+        match roster_status {
+            Some("INJURED_RESERVE") => return InjuryStatus::InjuredReserve,
+            Some("PUP" | "PHYSICALLY_UNABLE_TO_PERFORM") => return InjuryStatus::Pup,
+            _ => {}
+        }
+
+        let probability = match playing_probability.map(str::trim) {
+            Some(probability) if !probability.is_empty() => probability,
+            _ => return InjuryStatus::Unknown,
+        };
+
+        match probability.to_uppercase().as_str() {
+            "PROBABLE" => InjuryStatus::Probable,
+            "QUESTIONABLE" => InjuryStatus::Questionable,
+            "DOUBTFUL" => InjuryStatus::Doubtful,
+            "OUT" => InjuryStatus::Out,
+            _ => InjuryStatus::Other(probability.to_string()),
+        }
+    }
+}
+
+/// How much a player took part in the latest practice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PracticeParticipation {
+    Full,
+    Limited,
+    DidNotParticipate,
+}
+
+impl PracticeParticipation {
+    pub(crate) fn from_parsed(status: &str) -> Option<Self> {
+        match status.to_uppercase().as_str() {
+            "FULL" => Some(PracticeParticipation::Full),
+            "LIMITED" => Some(PracticeParticipation::Limited),
+            "DNP" | "DID_NOT_PARTICIPATE" => Some(PracticeParticipation::DidNotParticipate),
+            _ => None,
+        }
+    }
+}
+
+/// A player's current injury.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Injury {
+    /// Injured body part or description (e.g., "Knee")
+    pub body_part: String,
+    /// Availability designation
+    pub status: InjuryStatus,
+    /// Participation in the latest practice, if reported
+    pub practice: Option<PracticeParticipation>,
+    /// When the provider last updated the injury data
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
+impl Injury {
+    /// Builds the player's current injury, or `None` if the player is healthy.
+    pub(crate) fn from_parsed(info: &PlayerInfo, last_updated: Option<&str>) -> Option<Self> {
+        let injury = info.injury.as_ref();
+        let status = InjuryStatus::from_parsed(
+            injury.and_then(|i| i.playing_probability.as_deref()),
+            info.roster_status.as_deref(),
+        );
+
+        // Long-term lists are reported through roster status alone
+        if injury.is_none() && !matches!(status, InjuryStatus::InjuredReserve | InjuryStatus::Pup) {
+            return None;
+        }
+
+        Some(Self {
+            body_part: injury
+                .and_then(|i| i.description.clone())
+                .unwrap_or_default(),
+            status,
+            practice: injury
+                .and_then(|i| i.practice_status.as_deref())
+                .and_then(PracticeParticipation::from_parsed),
+            last_updated: last_updated.and_then(parse_timestamp),
+        })
+    }
+}
+
+/// An entry in the league injury report.
+
+#[derive(Debug, Clone)]
+pub struct InjuredPlayer {
+    /// The injured player
+    pub player: PlayerCandidate,
+    /// The player's current injury
+    pub injury: Injury,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parsers::player_parser::InjuriesResponse;
+
+    #[test]
+    fn test_injury_from_parsed() {
+        let json = r#"{
+            "lastUpdatedOn": "2024-10-04T14:30:00.000Z",
+            "players": [{
+                "player": {
+                    "id": 7549,
+                    "firstName": "Josh",
+                    "lastName": "Allen",
+                    "currentRosterStatus": "ROSTER",
+                    "currentInjury": { "description": "Hand", "playingProbability": "QUESTIONABLE", "practiceStatus": "LIMITED" }
+                }
+            }, {
+                "player": {
+                    "id": 8123,
+                    "currentRosterStatus": "INJURED_RESERVE",
+                    "currentInjury": { "description": "Achilles", "playingProbability": "OUT" }
+                }
+            }, {
+                "player": { "id": 9001, "currentRosterStatus": "ROSTER" }
+            }]
+        }"#;
+
+        let response: InjuriesResponse = serde_json::from_str(json).unwrap();
+        let updated = response.last_updated_on.as_deref();
+        let injuries: Vec<Option<Injury>> = response
+            .players
+            .iter()
+            .map(|entry| Injury::from_parsed(&entry.player_info, updated))
+            .collect();
+
+        let hand = injuries[0].as_ref().unwrap();
+        assert_eq!(hand.body_part, "Hand");
+        assert_eq!(hand.status, InjuryStatus::Questionable);
+        assert!(!hand.status.is_out());
+        assert_eq!(hand.practice, Some(PracticeParticipation::Limited));
+        assert_eq!(
+            hand.last_updated.unwrap().to_rfc3339(),
+            "2024-10-04T14:30:00+00:00"
+        );
+
+        let achilles = injuries[1].as_ref().unwrap();
+        assert_eq!(achilles.status, InjuryStatus::InjuredReserve);
+        assert!(achilles.status.is_out());
+
+        assert!(injuries[2].is_none());
+    }
+
+    #[test]
+    fn test_injury_status_from_parsed() {
+        assert_eq!(
+            InjuryStatus::from_parsed(Some("doubtful"), Some("ROSTER")),
+            InjuryStatus::Doubtful
+        );
+        assert_eq!(
+            InjuryStatus::from_parsed(None, Some("ROSTER")),
+            InjuryStatus::Unknown
+        );
+        assert_eq!(
+            InjuryStatus::from_parsed(Some(" "), None),
+            InjuryStatus::Unknown
+        );
+        assert_eq!(
+            InjuryStatus::from_parsed(Some("Game-Time Decision"), None),
+            InjuryStatus::Other("Game-Time Decision".to_string())
+        );
+        assert_eq!(
+            InjuryStatus::from_parsed(None, Some("PUP")),
+            InjuryStatus::Pup
+        );
+    }
+}
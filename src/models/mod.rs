@@ -1,9 +1,38 @@
+mod boxscore;
+mod depth_chart;
+mod directory;
 mod fetch;
+mod game;
+mod gamelog;
+mod injury;
 mod news;
+mod odds;
 pub(crate) mod parsers;
+mod play;
 mod player;
+mod search;
+mod standings;
+mod stats;
+mod team;
+mod transaction;
 
 // Re-export public types
+pub use boxscore::{Boxscore, BoxscorePlayer, QuarterScore, TeamBoxscore};
+pub use depth_chart::{DepthChart, DepthChartEntry, GameOrWeek};
+pub use directory::PlayerDirectory;
 pub use fetch::Season;
-pub use news::{Article, NewsQuery, PlayerNews};
-pub use player::{PlayerStats, PlayerSummary};
+pub use game::{Game, GameStatus, ScheduleFilter, Score};
+pub use gamelog::{GameLog, HomeAway};
+pub use injury::{InjuredPlayer, Injury, InjuryStatus, PracticeParticipation};
+pub use news::{Article, NewsQuery, PlayerNews, SearchIn};
+pub use odds::{GameLine, Moneyline, PlayerProp, Spread, Total};
+pub use play::{FieldPosition, Play, PlayParticipant, PlayRole, PlayType};
+pub use player::{PlayerCandidate, PlayerHint, PlayerProfile, PlayerStats, PlayerSummary};
+pub(crate) use search::PlayerIndex;
+pub use search::PlayerMatch;
+pub use standings::{Standings, Streak, TeamStanding};
+pub use stats::{
+    DefensiveLine, PassingLine, ReceivingLine, ReturnLine, RushingLine, SnapUsage, TwoPointLine,
+};
+pub use team::{Record, TeamDefense, TeamOffense, TeamStats};
+pub use transaction::{Transaction, TransactionKind};
@@ -1,9 +1,15 @@
+use crate::{
+    config::{NewsConfig, SortBy},
+    models::parsers::news_parser,
+    utils::parse_timestamp,
+};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 /// A news article about a player or team.
 ///
 /// This struct represents a single news article with metadata
-/// about publication time and content.
+/// about its source, author and publication time.
 
 #[derive(Debug, Clone)]
 pub struct Article {
@@ -11,27 +17,124 @@ pub struct Article {
     pub title: String,
     /// Brief description or summary of the article
     pub description: String,
-    /// Publication timestamp (ISO 8601 format)
-    pub published_at: String,
+    /// Canonical link to the article
+    pub url: String,
+    /// Publisher's identifier, when the news source assigns one (e.g., "espn")
+    pub source_id: Option<String>,
+    /// Publisher's display name (e.g., "ESPN")
+    pub source_name: String,
+    /// Article author, if credited
+    pub author: Option<String>,
+    /// Link to the article's lead image, if any
+    pub image_url: Option<String>,
+    /// Publication time
+    pub published_at: DateTime<Utc>,
     /// Full article content (may be truncated)
     pub content: String,
 }
 
+impl Article {
+    /// Builds an article, or `None` if it has no parsable publication time.
+    pub(crate) fn from_parsed(article: &news_parser::Article) -> Option<Self> {
+        let published_at = parse_timestamp(article.published_at.as_deref()?)?;
+        let source = article.source.as_ref();
+
+        Some(Self {
+            title: article.title.clone().unwrap_or_default(),
+            description: article.description.clone().unwrap_or_default(),
+            url: article.url.clone().unwrap_or_default(),
+            source_id: source.and_then(|source| source.id.clone()),
+            source_name: source
+                .and_then(|source| source.name.clone())
+                .unwrap_or_default(),
+            author: article.author.clone(),
+            image_url: article.url_to_image.clone(),
+            published_at,
+            content: article.content.clone().unwrap_or_default(),
+        })
+    }
+}
+
+/// Article field that query terms are matched against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchIn {
+    /// The headline
+    Title,
+    /// The summary
+    Description,
+    /// The article body
+    Content,
+}
+
+impl std::fmt::Display for SearchIn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchIn::Title => write!(f, "title"),
+            SearchIn::Description => write!(f, "description"),
+            SearchIn::Content => write!(f, "content"),
+        }
+    }
+}
+
 /// Query parameters for fetching news articles.
 ///
-/// This struct encapsulates the parameters used to search for news
-/// articles, including player name, date range, and result limits.
+/// A query is built around a player name and narrowed with required and
+/// excluded terms, the article fields to search, a publication date range,
+/// domains, sources and language. Multi-word names and terms are sent as
+/// exact phrases:
+///
+/// ```
+/// use statbook::NewsQuery;
+///
+/// let query = NewsQuery::for_player("Josh Allen")
+///     .with_required_term("Bills")
+///     .with_excluded_term("Bengals Josh Allen");
+///
+/// assert_eq!(
+///     query.query_string(),
+///     r#""Josh Allen" AND Bills NOT "Bengals Josh Allen""#
+/// );
+/// ```
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewsQuery {
     /// Name of the player to search for
     pub player_name: String,
-    /// Start date for news search (YYYY-MM-DD format)
-    pub from_date: String,
-    /// Maximum number of articles to return
+    /// Terms every article must also contain
+    #[serde(default)]
+    pub required_terms: Vec<String>,
+    /// Terms no article may contain
+    #[serde(default)]
+    pub excluded_terms: Vec<String>,
+    /// Fields the terms are matched against; empty searches all fields
+    #[serde(default)]
+    pub search_in: Vec<SearchIn>,
+    /// Earliest publication date to include
+    #[serde(default)]
+    pub from: Option<NaiveDate>,
+    /// Latest publication date to include
+    #[serde(default)]
+    pub to: Option<NaiveDate>,
+    /// Maximum number of articles to return per page
     pub page_size: u32,
-    /// Field to sort results by (e.g., "publishedAt")
-    pub sort_by: String,
+    /// Page of results to return, starting at 1
+    #[serde(default = "first_page")]
+    pub page: u32,
+    /// Order of the results
+    pub sort_by: SortBy,
+    /// ISO 639-1 language code to restrict results to (e.g., "en"); empty for any language
+    #[serde(default)]
+    pub language: String,
+    /// Domains to restrict results to (e.g., "espn.com")
+    #[serde(default)]
+    pub domains: Vec<String>,
+    /// Domains to leave out of the results
+    #[serde(default)]
+    pub exclude_domains: Vec<String>,
+    /// NewsAPI source IDs to restrict results to (e.g., "espn")
+    #[serde(default)]
+    pub sources: Vec<String>,
 }
 
 impl NewsQuery {
@@ -45,11 +148,34 @@ impl NewsQuery {
     ///
     /// * `name` - The player name to search for
     pub fn for_player(name: &str) -> Self {
+        Self::from_config(name, &NewsConfig::default())
+    }
+
+    /// Creates a news query for a player using the client's news settings.
+    ///
+    /// Page size, sort order and language come from `config`. A `from` date
+    /// `days_back` days before today is only set when `config.paid_tier` is
+    /// enabled, since the free tier rejects date filtering.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The player name to search for
+    /// * `config` - News settings to derive the query from
+    pub fn from_config(name: &str, config: &NewsConfig) -> Self {
         Self {
             player_name: name.to_string(),
-            from_date: String::new(), // Empty string = no date filter for free tier compatibility
-            page_size: 5,
-            sort_by: "publishedAt".to_string(),
+            required_terms: Vec::new(),
+            excluded_terms: Vec::new(),
+            search_in: Vec::new(),
+            from: config.from_date(Utc::now().date_naive()),
+            to: None,
+            page_size: config.max_articles,
+            page: first_page(),
+            sort_by: config.sort_by.clone(),
+            language: config.language.clone(),
+            domains: Vec::new(),
+            exclude_domains: Vec::new(),
+            sources: Vec::new(),
         }
     }
 
@@ -63,19 +189,178 @@ impl NewsQuery {
         self
     }
 
-    /// Sets the start date for the news search.
+    /// Selects which page of results to return.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Page number, starting at 1; each page holds `page_size` articles
+    pub fn with_page(mut self, page: u32) -> Self {
+        self.page = page.max(1);
+        self
+    }
+
+    /// Restricts results to articles published between two dates, inclusive.
     ///
     /// **Note:** Date filtering requires a paid NewsAPI subscription.
     /// The free tier will return a 426 error if this parameter is used.
     ///
     /// # Arguments
     ///
-    /// * `from_date` - Start date in YYYY-MM-DD format
-    pub fn with_date_range(mut self, from_date: String) -> Self {
-        self.from_date = from_date;
+    /// * `from` - Earliest publication date
+    /// * `to` - Latest publication date
+    pub fn with_date_range(mut self, from: NaiveDate, to: NaiveDate) -> Self {
+        self.from = Some(from);
+        self.to = Some(to);
+        self
+    }
+
+    /// Restricts results to articles published on or after a date.
+    ///
+    /// **Note:** Date filtering requires a paid NewsAPI subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Earliest publication date
+    pub fn with_from_date(mut self, from: NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the order of the results.
+    pub fn with_sort_by(mut self, sort_by: SortBy) -> Self {
+        self.sort_by = sort_by;
+        self
+    }
+
+    /// Restricts results to a language.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - ISO 639-1 language code (e.g., "en"), or empty for any language
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = language.to_string();
+        self
+    }
+
+    /// Adds a term every article must contain (joined with `AND`).
+    pub fn with_required_term(mut self, term: &str) -> Self {
+        self.required_terms.push(term.to_string());
+        self
+    }
+
+    /// Adds a term no article may contain (joined with `NOT`).
+    pub fn with_excluded_term(mut self, term: &str) -> Self {
+        self.excluded_terms.push(term.to_string());
+        self
+    }
+
+    /// Limits matching to the given article fields.
+    pub fn with_search_in(mut self, fields: &[SearchIn]) -> Self {
+        self.search_in = fields.to_vec();
         self
     }
+
+    /// Restricts results to the given domains (e.g., "espn.com").
+    pub fn with_domains(mut self, domains: &[&str]) -> Self {
+        self.domains = domains.iter().map(|domain| domain.to_string()).collect();
+        self
+    }
+
+    /// Leaves the given domains out of the results.
+    pub fn with_excluded_domains(mut self, domains: &[&str]) -> Self {
+        self.exclude_domains = domains.iter().map(|domain| domain.to_string()).collect();
+        self
+    }
+
+    /// Restricts results to the given NewsAPI source IDs (e.g., "espn").
+    pub fn with_sources(mut self, sources: &[&str]) -> Self {
+        self.sources = sources.iter().map(|source| source.to_string()).collect();
+        self
+    }
+
+    /// Builds the search expression sent as NewsAPI's `q` parameter.
+    ///
+    /// Terms containing whitespace are quoted so they match as exact phrases;
+    /// double quotes inside a term are dropped, and terms left empty are skipped.
+    pub fn query_string(&self) -> String {
+        let mut query = phrase(&self.player_name);
+        let terms = self
+            .required_terms
+            .iter()
+            .map(|term| (" AND ", term))
+            .chain(self.excluded_terms.iter().map(|term| (" NOT ", term)));
+        for (operator, term) in terms {
+            let term = phrase(term);
+            if !term.is_empty() {
+                query.push_str(operator);
+                query.push_str(&term);
+            }
+        }
+        query
+    }
+
+    /// NewsAPI `everything` parameters for this query, excluding the API key.
+    ///
+    /// Empty filters are left out so the request stays valid on the free tier.
+    pub(crate) fn query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("q", self.query_string()),
+            ("pageSize", self.page_size.to_string()),
+            ("sortBy", self.sort_by.to_string()),
+        ];
+
+        let lists = [
+            ("searchIn", join_fields(&self.search_in)),
+            ("domains", self.domains.join(",")),
+            ("excludeDomains", self.exclude_domains.join(",")),
+            ("sources", self.sources.join(",")),
+            ("language", self.language.clone()),
+        ];
+        params.extend(lists.into_iter().filter(|(_, value)| !value.is_empty()));
+
+        if self.page > 1 {
+            params.push(("page", self.page.to_string()));
+        }
+        if let Some(from) = self.from {
+            params.push(("from", from.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(to) = self.to {
+            params.push(("to", to.format("%Y-%m-%d").to_string()));
+        }
+
+        params
+    }
+}
+
+fn first_page() -> u32 {
+    1
+}
+
+/// Quotes a term containing whitespace so NewsAPI matches it as a phrase.
+///
+/// Double quotes inside the term are stripped first so they cannot
+/// unbalance the quoting.
+fn phrase(term: &str) -> String {
+    let term = term
+        .split(|c: char| c == '"' || c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if term.contains(' ') {
+        format!("\"{}\"", term)
+    } else {
+        term
+    }
+}
+
+fn join_fields(fields: &[SearchIn]) -> String {
+    fields
+        .iter()
+        .map(SearchIn::to_string)
+        .collect::<Vec<String>>()
+        .join(",")
 }
+
 /// Collection of news articles for a player.
 ///
 /// This struct wraps a collection of news articles with metadata
@@ -127,3 +412,128 @@ impl PlayerNews {
         self.articles.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parsers::news_parser::NewsResponse;
+
+    #[test]
+    fn test_article_from_parsed() {
+        let json = r#"{
+            "status": "ok",
+            "totalResults": 2,
+            "articles": [{
+                "source": { "id": "espn", "name": "ESPN" },
+                "author": "Alaina Getzenberg",
+                "title": "Bills' Josh Allen throws four TDs in win",
+                "description": "Allen accounted for four scores.",
+                "url": "https://www.espn.com/nfl/story/_/id/1",
+                "urlToImage": "https://a.espncdn.com/photo/1.jpg",
+                "publishedAt": "2024-09-09T02:15:00Z",
+                "content": "ORCHARD PARK, N.Y. -- Josh Allen..."
+            }, {
+                "source": { "id": null, "name": "Buffalo News" },
+                "title": "Undated article",
+                "url": "https://buffalonews.com/2",
+                "publishedAt": "yesterday"
+            }]
+        }"#;
+
+        let response: NewsResponse = serde_json::from_str(json).unwrap();
+        let articles: Vec<Article> = response
+            .articles
+            .iter()
+            .filter_map(Article::from_parsed)
+            .collect();
+
+        assert_eq!(articles.len(), 1);
+        let article = &articles[0];
+        assert_eq!(article.url, "https://www.espn.com/nfl/story/_/id/1");
+        assert_eq!(article.source_id.as_deref(), Some("espn"));
+        assert_eq!(article.source_name, "ESPN");
+        assert_eq!(article.author.as_deref(), Some("Alaina Getzenberg"));
+        assert_eq!(
+            article.image_url.as_deref(),
+            Some("https://a.espncdn.com/photo/1.jpg")
+        );
+        assert_eq!(
+            article.published_at.to_rfc3339(),
+            "2024-09-09T02:15:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_news_query_from_config() {
+        let config = NewsConfig::new()
+            .with_max_articles(15)
+            .with_sort_by(SortBy::Relevancy)
+            .with_language("de".to_string());
+
+        let query = NewsQuery::from_config("josh-allen", &config);
+        assert_eq!(query.page_size, 15);
+        assert_eq!(query.sort_by, SortBy::Relevancy);
+        assert_eq!(query.language, "de");
+        assert!(query.from.is_none());
+
+        let query = NewsQuery::from_config("josh-allen", &config.with_paid_tier(true));
+        assert!(query.from.is_some());
+    }
+
+    #[test]
+    fn test_news_query_params() {
+        let date = |day| NaiveDate::from_ymd_opt(2024, 9, day).unwrap();
+        let query = NewsQuery::for_player("Josh Allen")
+            .with_required_term("Bills")
+            .with_excluded_term("Bengals Josh Allen")
+            .with_search_in(&[SearchIn::Title, SearchIn::Description])
+            .with_domains(&["espn.com", "nfl.com"])
+            .with_excluded_domains(&["bengals.com"])
+            .with_sort_by(SortBy::Relevancy)
+            .with_date_range(date(1), date(30));
+
+        assert_eq!(
+            query.query_string(),
+            r#""Josh Allen" AND Bills NOT "Bengals Josh Allen""#
+        );
+
+        let params = query.query_params();
+        let param = |key| {
+            params
+                .iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, value)| value.as_str())
+        };
+        assert_eq!(param("searchIn"), Some("title,description"));
+        assert_eq!(param("domains"), Some("espn.com,nfl.com"));
+        assert_eq!(param("excludeDomains"), Some("bengals.com"));
+        assert_eq!(param("sortBy"), Some("relevancy"));
+        assert_eq!(param("language"), Some("en"));
+        assert_eq!(param("from"), Some("2024-09-01"));
+        assert_eq!(param("to"), Some("2024-09-30"));
+        assert_eq!(param("sources"), None);
+        assert_eq!(param("page"), None);
+        assert!(query
+            .with_page(3)
+            .query_params()
+            .contains(&("page", "3".to_string())));
+
+        let quoted = NewsQuery::for_player(r#"Josh "the QB" Allen"#)
+            .with_required_term(r#""Bills""#)
+            .with_excluded_term(r#"""#);
+        assert_eq!(quoted.query_string(), r#""Josh the QB Allen" AND Bills"#);
+
+        let minimal = NewsQuery::for_player("josh-allen").query_params();
+        assert_eq!(minimal.len(), 4);
+        assert!(minimal.contains(&("q", "josh-allen".to_string())));
+
+        // Queries saved before the newer fields existed still deserialize
+        let saved: NewsQuery = serde_json::from_str(
+            r#"{ "player_name": "josh-allen", "page_size": 5, "sort_by": "publishedAt" }"#,
+        )
+        .unwrap();
+        assert_eq!(saved.page, 1);
+        assert!(saved.required_terms.is_empty() && saved.to.is_none());
+        assert_eq!(saved.query_string(), "josh-allen");
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{
+    models::parsers::odds_parser::{BookLines, GameLinesEntry, OddsPrice},
+    utils::parse_timestamp,
+};
+use chrono::{DateTime, Utc};
+
+/// A point spread and the price of each side.
+///
+/// Spreads are quoted from the home team's perspective: a negative
+/// `home_spread` means the home team is favored.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Spread {
+    /// Points added to the home team's score (e.g., -3.5)
+    pub home_spread: f64,
+    /// American odds for the home side
+    pub home_price: i32,
+    /// American odds for the away side
+    pub away_price: i32,
+}
+
+/// Straight-up prices for each team in American odds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Moneyline {
+    /// American odds for the home team
+    pub home: i32,
+    /// American odds for the away team
+    pub away: i32,
+}
+
+/// An over/under on combined points and the price of each side.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Total {
+    /// Combined points line (e.g., 47.5)
+    pub points: f64,
+    /// American odds for the over
+    pub over_price: i32,
+    /// American odds for the under
+    pub under_price: i32,
+}
+
+/// One sportsbook's current lines for a game.
+///
+/// Each market is `None` when the book has not posted it or has only
+/// priced one side of it. When a book has moved a line, only the most
+/// recent quote is kept.
+
+#[derive(Debug, Clone)]
+pub struct GameLine {
+    /// Provider's numeric game ID
+    pub game_id: u64,
+    /// Home team abbreviation
+    pub home_team: String,
+    /// Away team abbreviation
+    pub away_team: String,
+    /// Sportsbook name (e.g., "FanDuel")
+    pub book: String,
+    /// Time of the most recent quote across all markets
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Point spread
+    pub spread: Option<Spread>,
+    /// Moneyline
+    pub moneyline: Option<Moneyline>,
+    /// Over/under
+    pub total: Option<Total>,
+}
+
+impl GameLine {
+    /// Implied points for the home team, derived from the spread and total.
+    pub fn implied_home_total(&self) -> Option<f64> {
+        let (spread, total) = (self.spread?, self.total?);
+        Some((total.points - spread.home_spread) / 2.0)
+    }
+
+    /// Implied points for the away team, derived from the spread and total.
+    pub fn implied_away_total(&self) -> Option<f64> {
+        let (spread, total) = (self.spread?, self.total?);
+        Some((total.points + spread.home_spread) / 2.0)
+    }
+
+    pub(crate) fn from_parsed(entry: &GameLinesEntry, lines: &BookLines) -> Self {
+        let game = &entry.game;
+        let money_line = latest(&lines.money_lines, |quote| quote.as_of_time.as_deref());
+        let point_spread = latest(&lines.point_spreads, |quote| quote.as_of_time.as_deref());
+        let over_under = latest(&lines.over_unders, |quote| quote.as_of_time.as_deref());
+
+        let moneyline = money_line
+            .and_then(|(_, quote)| quote.money_line.as_ref())
+            .and_then(|line| {
+                Some(Moneyline {
+                    home: american(&line.home_line)?,
+                    away: american(&line.away_line)?,
+                })
+            });
+        let spread = point_spread
+            .and_then(|(_, quote)| quote.point_spread.as_ref())
+            .and_then(|line| {
+                Some(Spread {
+                    home_spread: line
+                        .home_spread
+                        .or(line.away_spread.map(|spread| -spread))?,
+                    home_price: american(&line.home_line)?,
+                    away_price: american(&line.away_line)?,
+                })
+            });
+        let total = over_under
+            .and_then(|(_, quote)| quote.over_under.as_ref())
+            .and_then(|line| {
+                Some(Total {
+                    points: line.over_under?,
+                    over_price: american(&line.over_line)?,
+                    under_price: american(&line.under_line)?,
+                })
+            });
+
+        Self {
+            game_id: game.id.unwrap_or(0),
+            home_team: game.home_team_abbreviation.clone().unwrap_or_default(),
+            away_team: game.away_team_abbreviation.clone().unwrap_or_default(),
+            book: lines
+                .source
+                .as_ref()
+                .and_then(|source| source.name.clone())
+                .unwrap_or_default(),
+            updated_at: [
+                money_line.and_then(|(time, _)| time),
+                point_spread.and_then(|(time, _)| time),
+                over_under.and_then(|(time, _)| time),
+            ]
+            .into_iter()
+            .flatten()
+            .max(),
+            spread,
+            moneyline,
+            total,
+        }
+    }
+}
+
+/// A sportsbook's over/under on a player statistic.
+
+#[derive(Debug, Clone)]
+pub struct PlayerProp {
+    /// Provider's numeric game ID
+    pub game_id: u64,
+    /// Provider's numeric player ID
+    pub player_id: u64,
+    /// Player's first name
+    pub first_name: String,
+    /// Player's last name
+    pub last_name: String,
+    /// Statistic the prop is on (e.g., "passing_yards")
+    pub market: String,
+    /// Line for the statistic (e.g., 245.5)
+    pub line: f64,
+    /// American odds for the over
+    pub over_price: i32,
+    /// American odds for the under
+    pub under_price: i32,
+    /// Sportsbook name (e.g., "FanDuel")
+    pub book: String,
+    /// When the book last updated the prop
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Returns the most recently timestamped quote, with its parsed time.
+fn latest<T>(
+    quotes: &[T],
+    as_of_time: impl Fn(&T) -> Option<&str>,
+) -> Option<(Option<DateTime<Utc>>, &T)> {
+    quotes
+        .iter()
+        .map(|quote| (as_of_time(quote).and_then(parse_timestamp), quote))
+        .max_by_key(|(time, _)| *time)
+}
+
+/// American odds for a price, or `None` if the book did not quote that side.
+fn american(price: &Option<OddsPrice>) -> Option<i32> {
+    price.as_ref().and_then(|price| price.american)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parsers::odds_parser::GameLinesResponse;
+
+    #[test]
+    fn test_game_line_from_parsed() {
+        let json = r#"{
+            "gameLines": [{
+                "game": { "id": 133421, "awayTeamAbbreviation": "ARI", "homeTeamAbbreviation": "BUF" },
+                "lines": [{
+                    "source": { "name": "FanDuel" },
+                    "moneyLines": [
+                        { "asOfTime": "2024-09-01T12:00:00.000Z", "moneyLine": { "awayLine": { "american": 220 }, "homeLine": { "american": -270 } } }
+                    ],
+                    "pointSpreads": [
+                        { "asOfTime": "2024-09-01T12:00:00.000Z", "pointSpread": { "homeSpread": -6.0, "awaySpread": 6.0, "homeLine": { "american": -110 }, "awayLine": { "american": -110 } } },
+                        { "asOfTime": "2024-09-07T18:30:00.000Z", "pointSpread": { "homeSpread": -6.5, "awaySpread": 6.5, "homeLine": { "american": -108 }, "awayLine": { "american": -112 } } }
+                    ],
+                    "overUnders": [
+                        { "asOfTime": "2024-09-06T09:00:00.000Z", "overUnder": { "overUnder": 47.5, "overLine": { "american": -105 }, "underLine": { "american": -115 } } }
+                    ]
+                }]
+            }]
+        }"#;
+
+        let response: GameLinesResponse = serde_json::from_str(json).unwrap();
+        let entry = &response.game_lines[0];
+        let line = GameLine::from_parsed(entry, &entry.lines[0]);
+
+        assert_eq!(line.book, "FanDuel");
+        assert_eq!(line.home_team, "BUF");
+        assert_eq!(
+            line.moneyline,
+            Some(Moneyline {
+                home: -270,
+                away: 220
+            })
+        );
+        assert_eq!(line.spread.unwrap().home_spread, -6.5);
+        assert_eq!(line.spread.unwrap().away_price, -112);
+        assert_eq!(line.total.unwrap().points, 47.5);
+        assert_eq!(
+            line.updated_at.unwrap().to_rfc3339(),
+            "2024-09-07T18:30:00+00:00"
+        );
+        assert_eq!(line.implied_home_total(), Some(27.0));
+        assert_eq!(line.implied_away_total(), Some(20.5));
+    }
+
+    #[test]
+    fn test_game_line_skips_one_sided_markets() {
+        let json = r#"{
+            "gameLines": [{
+                "game": { "id": 133421, "awayTeamAbbreviation": "ARI", "homeTeamAbbreviation": "BUF" },
+                "lines": [{
+                    "source": { "name": "FanDuel" },
+                    "moneyLines": [
+                        { "asOfTime": "2024-09-01T12:00:00.000Z", "moneyLine": { "homeLine": { "american": -270 } } }
+                    ],
+                    "pointSpreads": [
+                        { "asOfTime": "2024-09-01T12:00:00.000Z", "pointSpread": { "homeSpread": -6.0, "homeLine": { "american": -110 }, "awayLine": { "american": -110 } } }
+                    ],
+                    "overUnders": [
+                        { "asOfTime": "2024-09-06T09:00:00.000Z", "overUnder": { "overUnder": 47.5, "overLine": {} } }
+                    ]
+                }]
+            }]
+        }"#;
+
+        let response: GameLinesResponse = serde_json::from_str(json).unwrap();
+        let entry = &response.game_lines[0];
+        let line = GameLine::from_parsed(entry, &entry.lines[0]);
+
+        assert!(line.moneyline.is_none());
+        assert!(line.total.is_none());
+        assert_eq!(line.spread.unwrap().home_price, -110);
+    }
+}
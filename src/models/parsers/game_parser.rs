@@ -0,0 +1,257 @@
+use crate::models::parsers::{
+    player_parser::{PlayerInfo, Statistics},
+    team_parser::TeamStatistics,
+};
+use serde::Deserialize;
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct GamesResponse {
+    #[serde(default)]
+    pub games: Vec<GameEntry>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct GameEntry {
+    #[serde(default)]
+    pub schedule: GameSchedule,
+    #[serde(default)]
+    pub score: Option<GameScore>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct GameSchedule {
+    #[serde(default)]
+    pub id: Option<u64>,
+    #[serde(default)]
+    pub week: Option<u32>,
+    #[serde(rename = "startTime", default)]
+    pub start_time: Option<String>,
+    #[serde(rename = "awayTeam", default)]
+    pub away_team: Option<TeamReference>,
+    #[serde(rename = "homeTeam", default)]
+    pub home_team: Option<TeamReference>,
+    #[serde(default)]
+    pub venue: Option<VenueReference>,
+    #[serde(rename = "scheduleStatus", default)]
+    pub schedule_status: Option<String>,
+    #[serde(rename = "playedStatus", default)]
+    pub played_status: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct TeamReference {
+    #[serde(default)]
+    pub id: Option<u64>,
+    #[serde(default)]
+    pub abbreviation: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct VenueReference {
+    #[serde(default)]
+    pub id: Option<u64>,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct GameScore {
+    #[serde(rename = "currentQuarter", default)]
+    pub current_quarter: Option<u32>,
+    #[serde(rename = "awayScoreTotal", default)]
+    pub away_score_total: Option<u32>,
+    #[serde(rename = "homeScoreTotal", default)]
+    pub home_score_total: Option<u32>,
+    #[serde(default)]
+    pub quarters: Vec<QuarterScoring>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct QuarterScoring {
+    #[serde(rename = "quarterNumber", default)]
+    pub quarter_number: Option<u32>,
+    #[serde(rename = "awayScore", default)]
+    pub away_score: Option<u32>,
+    #[serde(rename = "homeScore", default)]
+    pub home_score: Option<u32>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct BoxscoreResponse {
+    #[serde(default)]
+    pub game: GameSchedule,
+    #[serde(default)]
+    pub scoring: Option<GameScore>,
+    #[serde(default)]
+    pub stats: Option<BoxscoreStats>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct BoxscoreStats {
+    #[serde(default)]
+    pub away: BoxscoreTeam,
+    #[serde(default)]
+    pub home: BoxscoreTeam,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct BoxscoreTeam {
+    #[serde(rename = "teamStats", default)]
+    pub team_stats: Vec<TeamStatistics>,
+    #[serde(default)]
+    pub players: Vec<BoxscorePlayerEntry>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct BoxscorePlayerEntry {
+    #[serde(rename = "player", default)]
+    pub player_info: PlayerInfo,
+    #[serde(rename = "playerStats", default)]
+    pub player_stats: Vec<Statistics>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct PlayByPlayResponse {
+    #[serde(default)]
+    pub game: GameSchedule,
+    #[serde(default)]
+    pub plays: Vec<PlayEntry>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct PlayEntry {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(rename = "playStatus", default)]
+    pub play_status: PlayStatus,
+    #[serde(rename = "passingPlay", default)]
+    pub passing_play: Option<PlayDetail>,
+    #[serde(rename = "rushingPlay", default)]
+    pub rushing_play: Option<PlayDetail>,
+    #[serde(rename = "sackingPlay", default)]
+    pub sacking_play: Option<PlayDetail>,
+    #[serde(rename = "kickingPlay", default)]
+    pub kicking_play: Option<PlayDetail>,
+    #[serde(rename = "puntingPlay", default)]
+    pub punting_play: Option<PlayDetail>,
+    #[serde(rename = "fieldGoalAttempt", default)]
+    pub field_goal_attempt: Option<PlayDetail>,
+    #[serde(rename = "extraPointAttempt", default)]
+    pub extra_point_attempt: Option<PlayDetail>,
+    #[serde(rename = "twoPointAttempt", default)]
+    pub two_point_attempt: Option<PlayDetail>,
+    #[serde(default)]
+    pub penalty: Option<PlayDetail>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct PlayStatus {
+    #[serde(default)]
+    pub quarter: Option<u32>,
+    #[serde(rename = "secondsElapsed", default)]
+    pub seconds_elapsed: Option<u32>,
+    #[serde(rename = "teamInPossession", default)]
+    pub team_in_possession: Option<TeamReference>,
+    #[serde(rename = "currentDown", default)]
+    pub current_down: Option<u8>,
+    #[serde(rename = "yardsRemaining", default)]
+    pub yards_remaining: Option<u32>,
+    #[serde(rename = "lineOfScrimmage", default)]
+    pub line_of_scrimmage: Option<LineOfScrimmage>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct LineOfScrimmage {
+    #[serde(default)]
+    pub team: Option<TeamReference>,
+    #[serde(rename = "yardLine", default)]
+    pub yard_line: Option<u32>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct PlayDetail {
+    #[serde(default)]
+    pub team: Option<TeamReference>,
+    #[serde(rename = "passingPlayer", default)]
+    pub passing_player: Option<PlayerInfo>,
+    #[serde(rename = "receivingPlayer", default)]
+    pub receiving_player: Option<PlayerInfo>,
+    #[serde(rename = "rushingPlayer", default)]
+    pub rushing_player: Option<PlayerInfo>,
+    #[serde(rename = "sackingPlayer", default)]
+    pub sacking_player: Option<PlayerInfo>,
+    #[serde(rename = "kickingPlayer", default)]
+    pub kicking_player: Option<PlayerInfo>,
+    #[serde(rename = "puntingPlayer", default)]
+    pub punting_player: Option<PlayerInfo>,
+    #[serde(rename = "retrievingPlayer", default)]
+    pub retrieving_player: Option<PlayerInfo>,
+    #[serde(rename = "penalizedPlayer", default)]
+    pub penalized_player: Option<PlayerInfo>,
+    #[serde(rename = "totalYardsGained", default)]
+    pub total_yards_gained: Option<i32>,
+    #[serde(rename = "yardsRushed", default)]
+    pub yards_rushed: Option<i32>,
+    #[serde(rename = "yardsLost", default)]
+    pub yards_lost: Option<i32>,
+    #[serde(rename = "isCompleted", default)]
+    pub is_completed: Option<bool>,
+    #[serde(rename = "isGood", default)]
+    pub is_good: Option<bool>,
+    #[serde(rename = "isTouchdown", default)]
+    pub is_touchdown: Option<bool>,
+    #[serde(rename = "isSafety", default)]
+    pub is_safety: Option<bool>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct LineupResponse {
+    #[serde(default)]
+    pub game: GameSchedule,
+    #[serde(rename = "teamLineups", default)]
+    pub team_lineups: Vec<TeamLineup>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct TeamLineup {
+    #[serde(default)]
+    pub team: TeamReference,
+    #[serde(default)]
+    pub expected: Option<Lineup>,
+    #[serde(default)]
+    pub actual: Option<Lineup>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct Lineup {
+    #[serde(rename = "lineupPositions", default)]
+    pub lineup_positions: Vec<LineupPosition>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct LineupPosition {
+    #[serde(default)]
+    pub position: Option<String>,
+    #[serde(default)]
+    pub player: Option<PlayerInfo>,
+}
@@ -1,2 +1,5 @@
+pub(crate) mod game_parser;
 pub(crate) mod news_parser;
+pub(crate) mod odds_parser;
 pub(crate) mod player_parser;
+pub(crate) mod team_parser;
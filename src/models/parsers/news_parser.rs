@@ -9,6 +9,17 @@ pub(crate) struct NewsResponse {
     pub articles: Vec<Article>,
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct NewsErrorResponse {
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Deserialize, Default)]
 pub(crate) struct Article {
@@ -20,6 +31,8 @@ pub(crate) struct Article {
     pub title: Option<String>,
     #[serde(default)]
     pub description: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
     #[serde(rename = "urlToImage", default)]
     pub url_to_image: Option<String>,
     #[serde(rename = "publishedAt", default)]
@@ -0,0 +1,107 @@
+use crate::models::parsers::player_parser::GameReference;
+use serde::Deserialize;
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct GameLinesResponse {
+    #[serde(rename = "gameLines", default)]
+    pub game_lines: Vec<GameLinesEntry>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct GameLinesEntry {
+    #[serde(default)]
+    pub game: GameReference,
+    #[serde(default)]
+    pub lines: Vec<BookLines>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct BookLines {
+    #[serde(default)]
+    pub source: Option<OddsSource>,
+    #[serde(rename = "moneyLines", default)]
+    pub money_lines: Vec<MoneyLineEntry>,
+    #[serde(rename = "pointSpreads", default)]
+    pub point_spreads: Vec<PointSpreadEntry>,
+    #[serde(rename = "overUnders", default)]
+    pub over_unders: Vec<OverUnderEntry>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct OddsSource {
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct OddsPrice {
+    #[serde(default)]
+    pub american: Option<i32>,
+    #[serde(default)]
+    pub decimal: Option<f64>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct MoneyLineEntry {
+    #[serde(rename = "asOfTime", default)]
+    pub as_of_time: Option<String>,
+    #[serde(rename = "moneyLine", default)]
+    pub money_line: Option<MoneyLine>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct MoneyLine {
+    #[serde(rename = "awayLine", default)]
+    pub away_line: Option<OddsPrice>,
+    #[serde(rename = "homeLine", default)]
+    pub home_line: Option<OddsPrice>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct PointSpreadEntry {
+    #[serde(rename = "asOfTime", default)]
+    pub as_of_time: Option<String>,
+    #[serde(rename = "pointSpread", default)]
+    pub point_spread: Option<PointSpread>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct PointSpread {
+    #[serde(rename = "awaySpread", default)]
+    pub away_spread: Option<f64>,
+    #[serde(rename = "homeSpread", default)]
+    pub home_spread: Option<f64>,
+    #[serde(rename = "awayLine", default)]
+    pub away_line: Option<OddsPrice>,
+    #[serde(rename = "homeLine", default)]
+    pub home_line: Option<OddsPrice>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct OverUnderEntry {
+    #[serde(rename = "asOfTime", default)]
+    pub as_of_time: Option<String>,
+    #[serde(rename = "overUnder", default)]
+    pub over_under: Option<OverUnder>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct OverUnder {
+    #[serde(rename = "overUnder", default)]
+    pub over_under: Option<f64>,
+    #[serde(rename = "overLine", default)]
+    pub over_line: Option<OddsPrice>,
+    #[serde(rename = "underLine", default)]
+    pub under_line: Option<OddsPrice>,
+}
@@ -7,6 +7,57 @@ pub(crate) struct PlayerResponse {
     pub players: Vec<Player>,
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct PlayersResponse {
+    #[serde(default)]
+    pub players: Vec<PlayerEntry>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct PlayerEntry {
+    #[serde(rename = "player", default)]
+    pub player_info: PlayerInfo,
+    #[serde(rename = "teamAsOfDate", default)]
+    pub team_as_of_date: Option<TeamInfo>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct GamelogResponse {
+    #[serde(default)]
+    pub gamelogs: Vec<Gamelog>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct Gamelog {
+    #[serde(default)]
+    pub game: GameReference,
+    #[serde(rename = "player", default)]
+    pub player_info: PlayerInfo,
+    #[serde(rename = "team", default)]
+    pub team_info: TeamInfo,
+    #[serde(rename = "stats", default)]
+    pub statistics: Statistics,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct GameReference {
+    #[serde(default)]
+    pub id: Option<u64>,
+    #[serde(default)]
+    pub week: Option<u32>,
+    #[serde(rename = "startTime", default)]
+    pub start_time: Option<String>,
+    #[serde(rename = "awayTeamAbbreviation", default)]
+    pub away_team_abbreviation: Option<String>,
+    #[serde(rename = "homeTeamAbbreviation", default)]
+    pub home_team_abbreviation: Option<String>,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Deserialize, Default)]
 pub(crate) struct Player {
@@ -30,6 +81,8 @@ pub(crate) struct PlayerInfo {
     pub last_name: Option<String>,
     #[serde(rename = "primaryPosition", default)]
     pub primary_position: Option<String>,
+    #[serde(default)]
+    pub position: Option<String>,
     #[serde(rename = "jerseyNumber", default)]
     pub jersey_number: Option<u32>,
     #[serde(rename = "currentTeam", default)]
@@ -37,7 +90,7 @@ pub(crate) struct PlayerInfo {
     #[serde(rename = "currentRosterStatus", default)]
     pub roster_status: Option<String>,
     #[serde(rename = "currentInjury", default)]
-    pub injury: Option<String>,
+    pub injury: Option<InjuryInfo>,
     #[serde(default)]
     pub height: Option<String>,
     #[serde(default)]
@@ -62,6 +115,50 @@ pub(crate) struct PlayerInfo {
     pub official_image_src: Option<String>,
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct InjuryInfo {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(rename = "playingProbability", default)]
+    pub playing_probability: Option<String>,
+    #[serde(rename = "practiceStatus", default)]
+    pub practice_status: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct InjuriesResponse {
+    #[serde(rename = "lastUpdatedOn", default)]
+    pub last_updated_on: Option<String>,
+    #[serde(default)]
+    pub players: Vec<PlayerEntry>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct TransactionsResponse {
+    #[serde(default)]
+    pub transactions: Vec<TransactionEntry>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct TransactionEntry {
+    #[serde(rename = "asOfDate", default)]
+    pub as_of_date: Option<String>,
+    #[serde(rename = "type", default)]
+    pub transaction_type: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(rename = "player", default)]
+    pub player_info: PlayerInfo,
+    #[serde(rename = "fromTeam", default)]
+    pub from_team: Option<TeamInfo>,
+    #[serde(rename = "toTeam", default)]
+    pub to_team: Option<TeamInfo>,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Deserialize, Default)]
 pub(crate) struct TeamInfo {
@@ -111,6 +208,8 @@ pub(crate) struct PassingStats {
     pub percentage: Option<f64>,
     #[serde(rename = "passYards", default)]
     pub yards_total: Option<i64>,
+    #[serde(rename = "passNetYards", default)]
+    pub net_yards: Option<i64>,
     #[serde(rename = "passAvg", default)]
     pub yards_average: Option<f64>,
     #[serde(rename = "passYardsPerAtt", default)]
@@ -212,7 +311,7 @@ pub(crate) struct InterceptionStats {
     #[serde(rename = "intTD", default)]
     pub touchdowns: Option<i64>,
     #[serde(rename = "intYds", default)]
-    pub yards_todal: Option<i64>,
+    pub yards_total: Option<i64>,
     #[serde(rename = "intAverage", default)]
     pub yards_average: Option<f64>,
     #[serde(rename = "intLng", default)]
@@ -262,7 +361,7 @@ pub(crate) struct KickOffReturns {
     #[serde(rename = "krAvg", default)]
     pub yards_average: Option<f64>,
     #[serde(rename = "krLng", default)]
-    pub longest_kickoff_return: Option<i64>,
+    pub longest_return: Option<i64>,
     #[serde(rename = "krTD", default)]
     pub touchdowns: Option<i64>,
     #[serde(rename = "kr20Plus", default)]
@@ -270,7 +369,7 @@ pub(crate) struct KickOffReturns {
     #[serde(rename = "kr40Plus", default)]
     pub forty_plus_yards: Option<i64>,
     #[serde(rename = "krFC", default)]
-    pub catches: Option<i64>,
+    pub fair_catches: Option<i64>,
     #[serde(rename = "krFum", default)]
     pub fumbles: Option<i64>,
 }
@@ -279,13 +378,13 @@ pub(crate) struct KickOffReturns {
 #[derive(Debug, Deserialize, Default)]
 pub(crate) struct PuntReturns {
     #[serde(rename = "prRet", default)]
-    pub punt_returns: Option<i64>,
+    pub returns: Option<i64>,
     #[serde(rename = "prYds", default)]
     pub yards_total: Option<i64>,
     #[serde(rename = "prAvg", default)]
     pub yards_average: Option<f64>,
     #[serde(rename = "prLng", default)]
-    pub longest_punt_return: Option<i64>,
+    pub longest_return: Option<i64>,
     #[serde(rename = "prTD", default)]
     pub touchdowns: Option<i64>,
     #[serde(rename = "pr20Plus", default)]
@@ -293,7 +392,7 @@ pub(crate) struct PuntReturns {
     #[serde(rename = "pr40Plus", default)]
     pub forty_plus_yards: Option<i64>,
     #[serde(rename = "prFC", default)]
-    pub catches: Option<i64>,
+    pub fair_catches: Option<i64>,
     #[serde(rename = "prFum", default)]
     pub fumbles: Option<i64>,
 }
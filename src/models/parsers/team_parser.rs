@@ -0,0 +1,139 @@
+use crate::models::parsers::player_parser::{
+    FumbleStats, InterceptionStats, PassingStats, RushingStats, TackleStats,
+};
+use serde::Deserialize;
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct TeamStatsResponse {
+    #[serde(rename = "teamStatsTotals", default)]
+    pub teams: Vec<TeamStatsEntry>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct TeamStatsEntry {
+    #[serde(rename = "team", default)]
+    pub team_info: TeamDetails,
+    #[serde(rename = "stats", default)]
+    pub statistics: TeamStatistics,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct TeamDetails {
+    #[serde(default)]
+    pub id: Option<u64>,
+    #[serde(default)]
+    pub abbreviation: Option<String>,
+    #[serde(default)]
+    pub city: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct TeamStatistics {
+    #[serde(rename = "gamesPlayed", default)]
+    pub games_played: Option<u64>,
+    #[serde(rename = "passing", default)]
+    pub passing_stats: Option<PassingStats>,
+    #[serde(rename = "rushing", default)]
+    pub rushing_stats: Option<RushingStats>,
+    #[serde(rename = "tackles", default)]
+    pub tackles_stats: Option<TackleStats>,
+    #[serde(rename = "interceptions", default)]
+    pub interceptions_stats: Option<InterceptionStats>,
+    #[serde(rename = "fumbles", default)]
+    pub fumbles_stats: Option<FumbleStats>,
+    #[serde(rename = "miscellaneous", default)]
+    pub miscellaneous_stats: Option<TeamMiscellaneousStats>,
+    #[serde(rename = "standings", default)]
+    pub standings: Option<StandingsStats>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct TeamMiscellaneousStats {
+    #[serde(rename = "firstDownsTotal", default)]
+    pub first_downs: Option<i64>,
+    #[serde(rename = "offensePlays", default)]
+    pub offense_plays: Option<i64>,
+    #[serde(rename = "offenseYds", default)]
+    pub offense_yards: Option<i64>,
+    #[serde(rename = "thirdDowns", default)]
+    pub third_downs: Option<i64>,
+    #[serde(rename = "thirdDownsConverted", default)]
+    pub third_downs_converted: Option<i64>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct StandingsStats {
+    #[serde(default)]
+    pub wins: Option<u32>,
+    #[serde(default)]
+    pub losses: Option<u32>,
+    #[serde(default)]
+    pub ties: Option<u32>,
+    #[serde(rename = "winPct", default)]
+    pub win_percentage: Option<f64>,
+    #[serde(rename = "pointsFor", default)]
+    pub points_for: Option<i64>,
+    #[serde(rename = "pointsAgainst", default)]
+    pub points_against: Option<i64>,
+    #[serde(rename = "pointDifferential", default)]
+    pub point_differential: Option<i64>,
+    #[serde(default)]
+    pub streak: Option<String>,
+    #[serde(rename = "divisionWins", default)]
+    pub division_wins: Option<u32>,
+    #[serde(rename = "divisionLosses", default)]
+    pub division_losses: Option<u32>,
+    #[serde(rename = "divisionTies", default)]
+    pub division_ties: Option<u32>,
+    #[serde(rename = "conferenceWins", default)]
+    pub conference_wins: Option<u32>,
+    #[serde(rename = "conferenceLosses", default)]
+    pub conference_losses: Option<u32>,
+    #[serde(rename = "conferenceTies", default)]
+    pub conference_ties: Option<u32>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct StandingsResponse {
+    #[serde(default)]
+    pub teams: Vec<StandingsEntry>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct StandingsEntry {
+    #[serde(rename = "team", default)]
+    pub team_info: TeamDetails,
+    #[serde(rename = "stats", default)]
+    pub statistics: TeamStatistics,
+    #[serde(rename = "overallRank", default)]
+    pub overall_rank: Option<RankInfo>,
+    #[serde(rename = "conferenceRank", default)]
+    pub conference_rank: Option<RankInfo>,
+    #[serde(rename = "divisionRank", default)]
+    pub division_rank: Option<RankInfo>,
+    #[serde(rename = "playoffRank", default)]
+    pub playoff_rank: Option<RankInfo>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct RankInfo {
+    #[serde(rename = "conferenceName", default)]
+    pub conference_name: Option<String>,
+    #[serde(rename = "divisionName", default)]
+    pub division_name: Option<String>,
+    #[serde(rename = "appliesTo", default)]
+    pub applies_to: Option<String>,
+    #[serde(default)]
+    pub rank: Option<u32>,
+}
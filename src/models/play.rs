@@ -0,0 +1,301 @@
+use crate::models::parsers::{
+    game_parser::{PlayDetail, PlayEntry},
+    player_parser::PlayerInfo,
+};
+
+/// Distance from the goal line that counts as the red zone.
+const RED_ZONE_YARDS: u32 = 20;
+
+/// The kind of play that was run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayType {
+    Pass,
+    Rush,
+    Sack,
+    Kickoff,
+    Punt,
+    FieldGoal,
+    ExtraPoint,
+    TwoPoint,
+    Penalty,
+    /// Timeouts, end-of-quarter markers and anything else without a play detail
+    Other,
+}
+
+/// What a player did on a play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayRole {
+    Passer,
+    Receiver,
+    Rusher,
+    Sacker,
+    Kicker,
+    Punter,
+    Returner,
+    Penalized,
+}
+
+/// A player involved in a play.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayParticipant {
+    /// Provider's numeric player ID
+    pub player_id: u64,
+    /// Player's first name
+    pub first_name: String,
+    /// Player's last name
+    pub last_name: String,
+    /// What the player did on the play
+    pub role: PlayRole,
+}
+
+impl PlayParticipant {
+    fn from_parsed(info: &PlayerInfo, role: PlayRole) -> Self {
+        Self {
+            player_id: info.id.unwrap_or(0),
+            first_name: info.first_name.clone().unwrap_or_default(),
+            last_name: info.last_name.clone().unwrap_or_default(),
+            role,
+        }
+    }
+}
+
+/// A spot on the field, expressed as a yard line on one team's side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldPosition {
+    /// Abbreviation of the team whose half of the field the ball is in
+    pub side: String,
+    /// Yard line on that side (1-50)
+    pub yard_line: u32,
+}
+
+/// A single play from a game's play-by-play feed.
+///
+/// Down, distance and field position are `None` for plays that don't
+/// have them, such as kickoffs and extra points.
+
+#[derive(Debug, Clone)]
+pub struct Play {
+    /// Position of the play in the game, starting at 0
+    pub sequence: u32,
+    /// Quarter the play occurred in (5 and up for overtime)
+    pub quarter: u32,
+    /// Seconds elapsed in the quarter when the play started
+    pub seconds_elapsed: u32,
+    /// Abbreviation of the team with the ball
+    pub possession: String,
+    /// Down (1-4)
+    pub down: Option<u8>,
+    /// Yards needed for a first down
+    pub distance: Option<u32>,
+    /// Line of scrimmage
+    pub yard_line: Option<FieldPosition>,
+    /// Kind of play
+    pub play_type: PlayType,
+    /// Players involved in the play
+    pub players: Vec<PlayParticipant>,
+    /// Yards gained by the offense; 0 for kicks and penalties
+    pub yards_gained: i32,
+    /// Whether the play produced points
+    pub scoring: bool,
+    /// Provider's text description of the play
+    pub description: String,
+}
+
+impl Play {
+    /// Yards between the line of scrimmage and the goal line the offense
+    /// is attacking, or `None` if the yard line is missing or past 100.
+    pub fn yards_to_goal(&self) -> Option<u32> {
+        let position = self.yard_line.as_ref()?;
+        if position.side.eq_ignore_ascii_case(&self.possession) {
+            100u32.checked_sub(position.yard_line)
+        } else {
+            Some(position.yard_line).filter(|yards| *yards <= 100)
+        }
+    }
+
+    /// Returns true if the play started inside the opponent's 20-yard line.
+    pub fn is_red_zone(&self) -> bool {
+        matches!(self.yards_to_goal(), Some(yards) if yards <= RED_ZONE_YARDS)
+    }
+
+    pub(crate) fn from_parsed(sequence: u32, entry: &PlayEntry) -> Self {
+        let status = &entry.play_status;
+        let (play_type, detail) = Self::detail(entry);
+        let empty = PlayDetail::default();
+        let detail = detail.unwrap_or(&empty);
+
+        let yards_gained = match play_type {
+            PlayType::Pass if detail.is_completed == Some(true) => {
+                detail.total_yards_gained.unwrap_or(0)
+            }
+            PlayType::Rush => detail.yards_rushed.unwrap_or(0),
+            PlayType::Sack => -detail.yards_lost.unwrap_or(0).abs(),
+            _ => 0,
+        };
+        let scoring = detail.is_touchdown == Some(true)
+            || detail.is_safety == Some(true)
+            || (matches!(
+                play_type,
+                PlayType::FieldGoal | PlayType::ExtraPoint | PlayType::TwoPoint
+            ) && detail.is_good == Some(true));
+
+        let roles = [
+            (&detail.passing_player, PlayRole::Passer),
+            (&detail.receiving_player, PlayRole::Receiver),
+            (&detail.rushing_player, PlayRole::Rusher),
+            (&detail.sacking_player, PlayRole::Sacker),
+            (&detail.kicking_player, PlayRole::Kicker),
+            (&detail.punting_player, PlayRole::Punter),
+            (&detail.retrieving_player, PlayRole::Returner),
+            (&detail.penalized_player, PlayRole::Penalized),
+        ];
+
+        Self {
+            sequence,
+            quarter: status.quarter.unwrap_or(0),
+            seconds_elapsed: status.seconds_elapsed.unwrap_or(0),
+            possession: status
+                .team_in_possession
+                .as_ref()
+                .or(detail.team.as_ref())
+                .and_then(|team| team.abbreviation.clone())
+                .unwrap_or_default(),
+            down: status.current_down.filter(|down| *down > 0),
+            distance: status.yards_remaining,
+            yard_line: status.line_of_scrimmage.as_ref().and_then(|line| {
+                Some(FieldPosition {
+                    side: line.team.as_ref()?.abbreviation.clone()?,
+                    yard_line: line.yard_line?,
+                })
+            }),
+            play_type,
+            players: roles
+                .into_iter()
+                .filter_map(|(info, role)| {
+                    info.as_ref()
+                        .map(|info| PlayParticipant::from_parsed(info, role))
+                })
+                .collect(),
+            yards_gained,
+            scoring,
+            description: entry.description.clone().unwrap_or_default(),
+        }
+    }
+
+    fn detail(entry: &PlayEntry) -> (PlayType, Option<&PlayDetail>) {
+        let details = [
+            (PlayType::TwoPoint, &entry.two_point_attempt),
+            (PlayType::Pass, &entry.passing_play),
+            (PlayType::Rush, &entry.rushing_play),
+            (PlayType::Sack, &entry.sacking_play),
+            (PlayType::FieldGoal, &entry.field_goal_attempt),
+            (PlayType::ExtraPoint, &entry.extra_point_attempt),
+            (PlayType::Punt, &entry.punting_play),
+            (PlayType::Kickoff, &entry.kicking_play),
+            (PlayType::Penalty, &entry.penalty),
+        ];
+
+        details
+            .into_iter()
+            .find_map(|(play_type, detail)| detail.as_ref().map(|detail| (play_type, Some(detail))))
+            .unwrap_or((PlayType::Other, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parsers::game_parser::PlayByPlayResponse;
+
+    #[test]
+    fn test_play_from_parsed() {
+        let json = r#"{
+            "game": { "id": 133421 },
+            "plays": [{
+                "description": "J.Allen pass short right to D.Kincaid for 12 yards, TOUCHDOWN",
+                "playStatus": {
+                    "quarter": 2,
+                    "secondsElapsed": 410,
+                    "teamInPossession": { "abbreviation": "BUF" },
+                    "currentDown": 2,
+                    "yardsRemaining": 7,
+                    "lineOfScrimmage": { "team": { "abbreviation": "ARI" }, "yardLine": 12 }
+                },
+                "passingPlay": {
+                    "team": { "abbreviation": "BUF" },
+                    "passingPlayer": { "id": 7549, "firstName": "Josh", "lastName": "Allen" },
+                    "receivingPlayer": { "id": 19201, "firstName": "Dalton", "lastName": "Kincaid" },
+                    "totalYardsGained": 12,
+                    "isCompleted": true,
+                    "isTouchdown": true
+                }
+            }, {
+                "description": "J.Allen sacked at BUF 30 for -6 yards",
+                "playStatus": {
+                    "quarter": 3,
+                    "teamInPossession": { "abbreviation": "BUF" },
+                    "currentDown": 3,
+                    "yardsRemaining": 4,
+                    "lineOfScrimmage": { "team": { "abbreviation": "BUF" }, "yardLine": 36 }
+                },
+                "sackingPlay": { "yardsLost": 6 }
+            }, {
+                "description": "End of Quarter",
+                "playStatus": { "quarter": 3, "currentDown": 0 }
+            }]
+        }"#;
+
+        let response: PlayByPlayResponse = serde_json::from_str(json).unwrap();
+        let plays: Vec<Play> = response
+            .plays
+            .iter()
+            .enumerate()
+            .map(|(sequence, entry)| Play::from_parsed(sequence as u32, entry))
+            .collect();
+
+        let touchdown = &plays[0];
+        assert_eq!(touchdown.play_type, PlayType::Pass);
+        assert_eq!(touchdown.down, Some(2));
+        assert_eq!(touchdown.yards_gained, 12);
+        assert!(touchdown.scoring);
+        assert_eq!(touchdown.yards_to_goal(), Some(12));
+        assert!(touchdown.is_red_zone());
+        assert_eq!(touchdown.players.len(), 2);
+        assert_eq!(touchdown.players[1].role, PlayRole::Receiver);
+
+        let sack = &plays[1];
+        assert_eq!(sack.play_type, PlayType::Sack);
+        assert_eq!(sack.yards_gained, -6);
+        assert_eq!(sack.yards_to_goal(), Some(64));
+        assert!(!sack.is_red_zone());
+
+        assert_eq!(plays[2].play_type, PlayType::Other);
+        assert_eq!(plays[2].down, None);
+        assert_eq!(plays[2].sequence, 2);
+    }
+
+    #[test]
+    fn test_yards_to_goal_out_of_range() {
+        let json = r#"{
+            "game": { "id": 133421 },
+            "plays": [{
+                "playStatus": {
+                    "teamInPossession": { "abbreviation": "BUF" },
+                    "lineOfScrimmage": { "team": { "abbreviation": "BUF" }, "yardLine": 120 }
+                }
+            }, {
+                "playStatus": {
+                    "teamInPossession": { "abbreviation": "BUF" },
+                    "lineOfScrimmage": { "team": { "abbreviation": "ARI" }, "yardLine": 120 }
+                }
+            }]
+        }"#;
+
+        let response: PlayByPlayResponse = serde_json::from_str(json).unwrap();
+        for (sequence, entry) in response.plays.iter().enumerate() {
+            let play = Play::from_parsed(sequence as u32, entry);
+            assert_eq!(play.yards_to_goal(), None);
+            assert!(!play.is_red_zone());
+        }
+    }
+}
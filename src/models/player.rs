@@ -1,4 +1,17 @@
-use crate::models::news::Article;
+use crate::{
+    error::{Result, StatbookError},
+    models::{
+        injury::Injury,
+        news::Article,
+        parsers::player_parser::{Player, PlayerInfo},
+        stats::{
+            DefensiveLine, PassingLine, ReceivingLine, ReturnLine, RushingLine, SnapUsage,
+            TwoPointLine,
+        },
+    },
+    utils::parse_height_inches,
+};
+use chrono::NaiveDate;
 
 /// Player statistics without news articles.
 ///
@@ -6,8 +19,12 @@ use crate::models::news::Article;
 /// used when only statistical data is needed or as part of
 /// partial fetch results.
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct PlayerStats {
+    /// Provider's numeric player ID (stable across name spellings)
+    pub player_id: u64,
+    /// Provider's numeric ID of the current team (`None` if unsigned)
+    pub team_id: Option<u64>,
     /// Player's first name
     pub first_name: String,
     /// Player's last name
@@ -18,14 +35,71 @@ pub struct PlayerStats {
     pub jersey_number: u32,
     /// Current team name
     pub current_team: String,
-    /// Current injury status (empty if healthy)
-    pub injury: String,
+    /// Current injury (`None` if healthy)
+    pub injury: Option<Injury>,
     /// Whether this is the player's rookie season
     pub rookie: bool,
     /// Total games played this season
     pub games_played: u64,
     /// Season for which these stats apply
     pub season: String,
+    /// Passing totals (`None` if the player has no pass attempts)
+    pub passing: Option<PassingLine>,
+    /// Rushing totals (`None` if the player has no carries)
+    pub rushing: Option<RushingLine>,
+    /// Receiving totals (`None` if the player was never targeted)
+    pub receiving: Option<ReceivingLine>,
+    /// Defensive totals (`None` if the player recorded no defensive stats)
+    pub defense: Option<DefensiveLine>,
+    /// Kickoff return totals (`None` if the player returned no kickoffs)
+    pub kick_returns: Option<ReturnLine>,
+    /// Punt return totals (`None` if the player returned no punts)
+    pub punt_returns: Option<ReturnLine>,
+    /// Two-point conversion totals (`None` if the player had no two-point tries)
+    pub two_point: Option<TwoPointLine>,
+    /// Snap counts and games started (`None` if the feed has no usage data)
+    pub usage: Option<SnapUsage>,
+}
+
+impl PlayerStats {
+    pub(crate) fn from_parsed(player: &Player, season: &str) -> Self {
+        let player_info = &player.player_info;
+        let statistics = &player.statistics;
+
+        Self {
+            player_id: player_info.id.unwrap_or(0),
+            team_id: player_info.current_team.as_ref().and_then(|team| team.id),
+            first_name: player_info.first_name.clone().unwrap_or_default(),
+            last_name: player_info.last_name.clone().unwrap_or_default(),
+            primary_position: player_info.primary_position.clone().unwrap_or_default(),
+            jersey_number: player_info.jersey_number.unwrap_or(0),
+            current_team: player_info
+                .current_team
+                .as_ref()
+                .and_then(|team| team.abbreviation.clone())
+                .unwrap_or_default(),
+            injury: Injury::from_parsed(player_info, None),
+            rookie: player_info.rookie.unwrap_or(false),
+            games_played: statistics.games_played.unwrap_or(0),
+            season: season.to_string(),
+            passing: PassingLine::from_parsed(statistics.passing_stats.as_ref()),
+            rushing: RushingLine::from_parsed(statistics.rushing_stats.as_ref()),
+            receiving: ReceivingLine::from_parsed(statistics.receiving_stats.as_ref()),
+            defense: DefensiveLine::from_parsed(
+                statistics.tackles_stats.as_ref(),
+                statistics.interceptions_stats.as_ref(),
+                statistics.fumbles_stats.as_ref(),
+            ),
+            kick_returns: ReturnLine::from_kickoffs(statistics.kick_off_returns.as_ref()),
+            punt_returns: ReturnLine::from_punts(statistics.punt_returns.as_ref()),
+            two_point: TwoPointLine::from_parsed(statistics.two_point_attempts.as_ref()),
+            usage: SnapUsage::from_parsed(
+                statistics.snap_counts.as_ref(),
+                statistics.miscellaneous_stats.as_ref(),
+                statistics.games_played.unwrap_or(0),
+            ),
+        }
+    }
 }
 
 /// Quick summary of essential player information with news.
@@ -36,6 +110,10 @@ pub struct PlayerStats {
 
 #[derive(Debug, Clone)]
 pub struct PlayerSummary {
+    /// Provider's numeric player ID (stable across name spellings)
+    pub player_id: u64,
+    /// Provider's numeric ID of the current team (`None` if unsigned)
+    pub team_id: Option<u64>,
     /// Player's first name
     pub first_name: String,
     /// Player's last name
@@ -51,3 +129,277 @@ pub struct PlayerSummary {
     /// Related news articles
     pub news: Vec<Article>,
 }
+
+/// A player that matched an ambiguous name lookup.
+///
+/// Returned inside `StatbookError::AmbiguousPlayer` so callers can pick the
+/// right player and retry by ID or with a `PlayerHint`.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerCandidate {
+    /// Provider's numeric player ID
+    pub player_id: u64,
+    /// Player's first name
+    pub first_name: String,
+    /// Player's last name
+    pub last_name: String,
+    /// Current team abbreviation
+    pub current_team: String,
+    /// Primary playing position
+    pub primary_position: String,
+}
+
+impl PlayerCandidate {
+    pub(crate) fn from_parsed(info: &PlayerInfo) -> Self {
+        Self {
+            player_id: info.id.unwrap_or(0),
+            first_name: info.first_name.clone().unwrap_or_default(),
+            last_name: info.last_name.clone().unwrap_or_default(),
+            current_team: info
+                .current_team
+                .as_ref()
+                .and_then(|team| team.abbreviation.clone())
+                .unwrap_or_default(),
+            primary_position: info.primary_position.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&PlayerStats> for PlayerCandidate {
+    fn from(stats: &PlayerStats) -> Self {
+        Self {
+            player_id: stats.player_id,
+            first_name: stats.first_name.clone(),
+            last_name: stats.last_name.clone(),
+            current_team: stats.current_team.clone(),
+            primary_position: stats.primary_position.clone(),
+        }
+    }
+}
+
+/// Optional hint used to pick one player when several share a name.
+///
+/// Team and position comparisons are case-insensitive. An empty hint
+/// matches every player.
+
+#[derive(Debug, Clone, Default)]
+pub struct PlayerHint {
+    /// Team abbreviation the player must currently be on (e.g., "BUF")
+    pub team: Option<String>,
+    /// Primary position the player must have (e.g., "QB")
+    pub position: Option<String>,
+}
+
+impl PlayerHint {
+    /// Creates an empty hint that matches every player.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts matches to players on the given team.
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - Team abbreviation (e.g., "BUF")
+    pub fn with_team<S: Into<String>>(mut self, team: S) -> Self {
+        self.team = Some(team.into());
+        self
+    }
+
+    /// Restricts matches to players at the given position.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Position abbreviation (e.g., "QB")
+    pub fn with_position<S: Into<String>>(mut self, position: S) -> Self {
+        self.position = Some(position.into());
+        self
+    }
+
+    /// Returns true if the player satisfies every part of this hint.
+    pub fn matches(&self, stats: &PlayerStats) -> bool {
+        let team_matches = match &self.team {
+            Some(team) => team.eq_ignore_ascii_case(&stats.current_team),
+            None => true,
+        };
+        let position_matches = match &self.position {
+            Some(position) => position.eq_ignore_ascii_case(&stats.primary_position),
+            None => true,
+        };
+
+        team_matches && position_matches
+    }
+
+    /// Narrows the players returned for `name` down to exactly one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PlayerNotFound` if no candidate matches the hint and
+    /// `AmbiguousPlayer` if more than one does.
+    pub(crate) fn resolve(&self, name: &str, candidates: Vec<PlayerStats>) -> Result<PlayerStats> {
+        let mut matches: Vec<PlayerStats> = candidates
+            .into_iter()
+            .filter(|stats| self.matches(stats))
+            .collect();
+
+        match matches.len() {
+            0 => Err(StatbookError::PlayerNotFound {
+                name: name.to_string(),
+            }),
+            1 => Ok(matches.remove(0)),
+            _ => Err(StatbookError::AmbiguousPlayer {
+                name: name.to_string(),
+                candidates: matches.iter().map(PlayerCandidate::from).collect(),
+            }),
+        }
+    }
+}
+
+/// Roster statuses of players who are no longer in the league.
+const INACTIVE_ROSTER_STATUSES: [&str; 1] = ["RETIRED"];
+
+/// Biographical profile of a player.
+///
+/// This struct contains identity and bio details used for player cards,
+/// independent of any season's statistics.
+
+#[derive(Debug, Clone, Default)]
+pub struct PlayerProfile {
+    /// Provider's numeric player ID
+    pub player_id: u64,
+    /// Player's first name
+    pub first_name: String,
+    /// Player's last name
+    pub last_name: String,
+    /// Primary playing position
+    pub primary_position: String,
+    /// Jersey number
+    pub jersey_number: u32,
+    /// Current team abbreviation (empty if unsigned)
+    pub current_team: String,
+    /// Current roster status as reported by the provider (e.g., "ROSTER")
+    pub roster_status: String,
+    /// Whether this is the player's rookie season
+    pub rookie: bool,
+    /// Height in inches
+    pub height_inches: Option<u32>,
+    /// Weight in pounds
+    pub weight: Option<u32>,
+    /// Date of birth
+    pub birth_date: Option<NaiveDate>,
+    /// Age in years
+    pub age: Option<u32>,
+    /// City of birth
+    pub birth_city: Option<String>,
+    /// Country of birth
+    pub birth_country: Option<String>,
+    /// High school attended
+    pub high_school: Option<String>,
+    /// College attended
+    pub college: Option<String>,
+    /// URL of the player's official headshot
+    pub headshot_url: Option<String>,
+}
+
+impl PlayerProfile {
+    /// Returns true unless the player's roster status marks them as retired.
+    ///
+    /// Free agents and players on reserve lists count as active, whether or
+    /// not they are currently signed to a team.
+    pub fn is_active(&self) -> bool {
+        !INACTIVE_ROSTER_STATUSES
+            .iter()
+            .any(|status| self.roster_status.eq_ignore_ascii_case(status))
+    }
+
+    pub(crate) fn from_parsed(info: &PlayerInfo) -> Self {
+        Self {
+            player_id: info.id.unwrap_or(0),
+            first_name: info.first_name.clone().unwrap_or_default(),
+            last_name: info.last_name.clone().unwrap_or_default(),
+            primary_position: info.primary_position.clone().unwrap_or_default(),
+            jersey_number: info.jersey_number.unwrap_or(0),
+            current_team: info
+                .current_team
+                .as_ref()
+                .and_then(|team| team.abbreviation.clone())
+                .unwrap_or_default(),
+            roster_status: info.roster_status.clone().unwrap_or_default(),
+            rookie: info.rookie.unwrap_or(false),
+            height_inches: info.height.as_deref().and_then(parse_height_inches),
+            weight: info.weight,
+            birth_date: info
+                .birth_date
+                .as_deref()
+                .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()),
+            age: info.age,
+            birth_city: info.birth_city.clone(),
+            birth_country: info.birth_country.clone(),
+            high_school: info.high_school.clone(),
+            college: info.college.clone(),
+            headshot_url: info.official_image_src.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(player_id: u64, team: &str, position: &str) -> PlayerStats {
+        PlayerStats {
+            player_id,
+            first_name: "Josh".to_string(),
+            last_name: "Allen".to_string(),
+            current_team: team.to_string(),
+            primary_position: position.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_hint_resolves_ambiguous_names() {
+        let candidates = vec![stats(7549, "BUF", "QB"), stats(8102, "JAX", "LB")];
+
+        match PlayerHint::new().resolve("josh-allen", candidates.clone()) {
+            Err(StatbookError::AmbiguousPlayer { candidates, .. }) => {
+                assert_eq!(candidates.len(), 2);
+                assert_eq!(candidates[1].current_team, "JAX");
+            }
+            other => panic!("expected AmbiguousPlayer, got {other:?}"),
+        }
+
+        let qb = PlayerHint::new()
+            .with_position("qb")
+            .resolve("josh-allen", candidates.clone())
+            .unwrap();
+        assert_eq!(qb.player_id, 7549);
+
+        let missing = PlayerHint::new()
+            .with_team("NYJ")
+            .resolve("josh-allen", candidates);
+        assert!(matches!(missing, Err(StatbookError::PlayerNotFound { .. })));
+    }
+
+    #[test]
+    fn test_profile_from_parsed() {
+        let info = PlayerInfo {
+            id: Some(7549),
+            first_name: Some("Josh".to_string()),
+            last_name: Some("Allen".to_string()),
+            height: Some("6'5\"".to_string()),
+            weight: Some(237),
+            birth_date: Some("1996-05-21".to_string()),
+            college: Some("Wyoming".to_string()),
+            ..Default::default()
+        };
+
+        let profile = PlayerProfile::from_parsed(&info);
+        assert_eq!(profile.player_id, 7549);
+        assert_eq!(profile.height_inches, Some(77));
+        assert_eq!(profile.weight, Some(237));
+        assert_eq!(profile.birth_date, NaiveDate::from_ymd_opt(1996, 5, 21));
+        assert_eq!(profile.college.as_deref(), Some("Wyoming"));
+        assert_eq!(profile.headshot_url, None);
+    }
+}
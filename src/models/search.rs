@@ -0,0 +1,155 @@
+use crate::{
+    models::player::PlayerProfile,
+    utils::{normalize_name, similarity},
+};
+
+/// Minimum similarity score for a player to be returned by a search.
+const MIN_SCORE: f64 = 0.6;
+
+/// A player returned by a fuzzy name search.
+///
+/// Results are ranked by `score`, where 1.0 is an exact match after
+/// normalization (case, punctuation, accents and suffixes ignored).
+
+#[derive(Debug, Clone)]
+pub struct PlayerMatch {
+    /// Profile of the matched player
+    pub profile: PlayerProfile,
+    /// Similarity between the query and the player's name (0.0-1.0)
+    pub score: f64,
+}
+
+/// Searchable index of active players with pre-normalized names.
+pub(crate) struct PlayerIndex {
+    entries: Vec<IndexedPlayer>,
+}
+
+struct IndexedPlayer {
+    full_name: String,
+    compact_name: String,
+    first_name: String,
+    last_name: String,
+    profile: PlayerProfile,
+}
+
+impl PlayerIndex {
+    /// Builds an index from the provider's player list, keeping only active players.
+    pub(crate) fn new(players: Vec<PlayerProfile>) -> Self {
+        let entries = players
+            .into_iter()
+            .filter(PlayerProfile::is_active)
+            .map(|profile| {
+                let first_name = normalize_name(&profile.first_name);
+                let last_name = normalize_name(&profile.last_name);
+                let full_name = format!("{first_name} {last_name}");
+                IndexedPlayer {
+                    compact_name: full_name.replace(' ', ""),
+                    full_name,
+                    first_name,
+                    last_name,
+                    profile,
+                }
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Returns up to `limit` players whose names resemble `query`, best match first.
+    pub(crate) fn search(&self, query: &str, limit: usize) -> Vec<PlayerMatch> {
+        let query = normalize_name(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let compact_query = query.replace(' ', "");
+
+        let mut matches: Vec<PlayerMatch> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let score = entry.score(&query, &compact_query);
+                (score >= MIN_SCORE).then(|| PlayerMatch {
+                    profile: entry.profile.clone(),
+                    score,
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.profile.last_name.cmp(&b.profile.last_name))
+        });
+        matches.truncate(limit);
+        matches
+    }
+}
+
+impl IndexedPlayer {
+    fn score(&self, query: &str, compact_query: &str) -> f64 {
+        // Compare with and without spaces so "Ja Marr Chase" still matches "jamarr chase"
+        let full =
+            similarity(query, &self.full_name).max(similarity(compact_query, &self.compact_name));
+
+        if query.contains(' ') {
+            return full;
+        }
+
+        // Single-word queries are most likely a last name, occasionally a first name
+        full.max(similarity(query, &self.last_name) * 0.9)
+            .max(similarity(query, &self.first_name) * 0.8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(player_id: u64, first_name: &str, last_name: &str) -> PlayerProfile {
+        PlayerProfile {
+            player_id,
+            first_name: first_name.to_string(),
+            last_name: last_name.to_string(),
+            current_team: "CIN".to_string(),
+            roster_status: "ROSTER".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_search_handles_punctuation_accents_and_typos() {
+        let index = PlayerIndex::new(vec![
+            profile(1, "Ja'Marr", "Chase"),
+            profile(2, "Odell", "Beckham Jr."),
+            profile(3, "Kadarius", "Toney"),
+            profile(4, "Amon-Ra", "St. Brown"),
+            profile(5, "José", "Ramírez"),
+        ]);
+
+        assert_eq!(index.search("jamarr chase", 5)[0].profile.player_id, 1);
+        assert_eq!(index.search("Ja'Marr Chase", 5)[0].score, 1.0);
+        assert_eq!(index.search("Odell Beckham", 5)[0].profile.player_id, 2);
+        assert_eq!(index.search("amon ra st brown", 5)[0].profile.player_id, 4);
+        assert_eq!(index.search("jose ramirez", 5)[0].profile.player_id, 5);
+        assert_eq!(index.search("Kadarious Tony", 5)[0].profile.player_id, 3);
+        assert_eq!(index.search("chase", 5)[0].profile.player_id, 1);
+        assert!(index.search("patrick mahomes", 5).is_empty());
+    }
+
+    #[test]
+    fn test_index_skips_inactive_players() {
+        // Retired players stay excluded even with a stale team on file
+        let mut retired = profile(6, "Tom", "Brady");
+        retired.current_team = "TB".to_string();
+        retired.roster_status = "RETIRED".to_string();
+
+        // Free agents are unsigned but still active
+        let mut free_agent = profile(7, "Stefon", "Diggs");
+        free_agent.current_team.clear();
+        free_agent.roster_status = "UFA".to_string();
+
+        let index = PlayerIndex::new(vec![retired, free_agent]);
+        assert!(index.search("tom brady", 5).is_empty());
+        assert_eq!(index.search("stefon diggs", 5)[0].profile.player_id, 7);
+    }
+}
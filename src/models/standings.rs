@@ -0,0 +1,284 @@
+use crate::models::{
+    parsers::team_parser::{StandingsEntry, StandingsStats},
+    team::Record,
+};
+
+/// A team's current run of consecutive results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Streak {
+    Won(u32),
+    Lost(u32),
+    Tied(u32),
+}
+
+impl Streak {
+    /// Parses a provider streak such as "W3" or "L1".
+    pub(crate) fn from_parsed(streak: &str) -> Option<Self> {
+        let streak = streak.trim();
+        let (kind, length) = streak.split_at(streak.char_indices().nth(1)?.0);
+        let length = length.parse().ok()?;
+
+        match kind.to_ascii_uppercase().as_str() {
+            "W" => Some(Streak::Won(length)),
+            "L" => Some(Streak::Lost(length)),
+            "T" => Some(Streak::Tied(length)),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Streak {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Streak::Won(length) => write!(f, "W{length}"),
+            Streak::Lost(length) => write!(f, "L{length}"),
+            Streak::Tied(length) => write!(f, "T{length}"),
+        }
+    }
+}
+
+/// A team's place in the standings.
+
+#[derive(Debug, Clone, Default)]
+pub struct TeamStanding {
+    /// Provider's numeric team ID
+    pub team_id: u64,
+    /// Team abbreviation (e.g., "BUF")
+    pub abbreviation: String,
+    /// Team city (e.g., "Buffalo")
+    pub city: String,
+    /// Team name (e.g., "Bills")
+    pub name: String,
+    /// Conference name (e.g., "AFC")
+    pub conference: String,
+    /// Division name (e.g., "AFC East")
+    pub division: String,
+    /// Overall win-loss-tie record
+    pub record: Record,
+    /// Points scored
+    pub points_for: i64,
+    /// Points allowed
+    pub points_against: i64,
+    /// Current streak, if reported
+    pub streak: Option<Streak>,
+    /// Record against division opponents, if reported
+    pub division_record: Option<Record>,
+    /// Record against conference opponents, if reported
+    pub conference_record: Option<Record>,
+    /// Rank within the division
+    pub division_rank: u32,
+    /// Rank within the conference
+    pub conference_rank: u32,
+    /// Playoff seed (`None` if currently outside the playoff picture)
+    pub seed: Option<u32>,
+}
+
+impl TeamStanding {
+    /// Win percentage (0.0-1.0), counting ties as half a win.
+    pub fn win_pct(&self) -> Option<f64> {
+        self.record.win_pct()
+    }
+
+    /// Points scored minus points allowed.
+    pub fn point_differential(&self) -> i64 {
+        self.points_for - self.points_against
+    }
+
+    pub(crate) fn from_parsed(entry: &StandingsEntry, playoff_spots: u32) -> Self {
+        let team = &entry.team_info;
+        let standings = entry.statistics.standings.as_ref();
+        let division = entry.division_rank.as_ref();
+        let conference = entry.conference_rank.as_ref();
+
+        Self {
+            team_id: team.id.unwrap_or(0),
+            abbreviation: team.abbreviation.clone().unwrap_or_default(),
+            city: team.city.clone().unwrap_or_default(),
+            name: team.name.clone().unwrap_or_default(),
+            conference: conference
+                .and_then(|rank| rank.conference_name.clone())
+                .unwrap_or_default(),
+            division: division
+                .and_then(|rank| rank.division_name.clone())
+                .unwrap_or_default(),
+            record: standings.map(Record::from_parsed).unwrap_or_default(),
+            points_for: standings.and_then(|s| s.points_for).unwrap_or(0),
+            points_against: standings.and_then(|s| s.points_against).unwrap_or(0),
+            streak: standings
+                .and_then(|s| s.streak.as_deref())
+                .and_then(Streak::from_parsed),
+            division_record: standings.and_then(division_record),
+            conference_record: standings.and_then(conference_record),
+            division_rank: division.and_then(|rank| rank.rank).unwrap_or(0),
+            conference_rank: conference.and_then(|rank| rank.rank).unwrap_or(0),
+            seed: entry
+                .playoff_rank
+                .as_ref()
+                .and_then(|rank| rank.rank)
+                .filter(|rank| *rank <= playoff_spots),
+        }
+    }
+}
+
+fn division_record(standings: &StandingsStats) -> Option<Record> {
+    sub_record(
+        standings.division_wins,
+        standings.division_losses,
+        standings.division_ties,
+    )
+}
+
+fn conference_record(standings: &StandingsStats) -> Option<Record> {
+    sub_record(
+        standings.conference_wins,
+        standings.conference_losses,
+        standings.conference_ties,
+    )
+}
+
+fn sub_record(wins: Option<u32>, losses: Option<u32>, ties: Option<u32>) -> Option<Record> {
+    if wins.is_none() && losses.is_none() {
+        return None;
+    }
+
+    Some(Record {
+        wins: wins.unwrap_or(0),
+        losses: losses.unwrap_or(0),
+        ties: ties.unwrap_or(0),
+    })
+}
+
+/// League standings for a season.
+///
+/// Division and conference views are ordered by rank; all name
+/// comparisons are case-insensitive.
+
+#[derive(Debug, Clone, Default)]
+pub struct Standings {
+    /// Every team in the league
+    pub teams: Vec<TeamStanding>,
+    /// Season for which these standings apply
+    pub season: String,
+}
+
+impl Standings {
+    /// Number of playoff seeds per conference.
+    pub const PLAYOFF_SPOTS: u32 = 7;
+
+    /// Returns the teams in a division, best first.
+    ///
+    /// # Arguments
+    ///
+    /// * `division` - Division name (e.g., "AFC East")
+    pub fn division(&self, division: &str) -> Vec<&TeamStanding> {
+        let mut teams: Vec<&TeamStanding> = self
+            .teams
+            .iter()
+            .filter(|team| team.division.eq_ignore_ascii_case(division))
+            .collect();
+        teams.sort_by_key(|team| team.division_rank);
+        teams
+    }
+
+    /// Returns the teams in a conference, best first.
+    ///
+    /// # Arguments
+    ///
+    /// * `conference` - Conference name (e.g., "AFC")
+    pub fn conference(&self, conference: &str) -> Vec<&TeamStanding> {
+        let mut teams: Vec<&TeamStanding> = self
+            .teams
+            .iter()
+            .filter(|team| team.conference.eq_ignore_ascii_case(conference))
+            .collect();
+        teams.sort_by_key(|team| team.conference_rank);
+        teams
+    }
+
+    /// Returns a conference's seeded teams in seed order.
+    ///
+    /// # Arguments
+    ///
+    /// * `conference` - Conference name (e.g., "NFC")
+    pub fn playoff_picture(&self, conference: &str) -> Vec<&TeamStanding> {
+        let mut teams: Vec<&TeamStanding> = self
+            .conference(conference)
+            .into_iter()
+            .filter(|team| team.seed.is_some())
+            .collect();
+        teams.sort_by_key(|team| team.seed);
+        teams
+    }
+
+    /// Returns the standing for a team by abbreviation.
+    pub fn team(&self, abbreviation: &str) -> Option<&TeamStanding> {
+        self.teams
+            .iter()
+            .find(|team| team.abbreviation.eq_ignore_ascii_case(abbreviation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parsers::team_parser::StandingsResponse;
+
+    #[test]
+    fn test_standings_from_parsed() {
+        let json = r#"{
+            "teams": [{
+                "team": { "id": 48, "abbreviation": "BUF", "city": "Buffalo", "name": "Bills" },
+                "stats": {
+                    "standings": {
+                        "wins": 13, "losses": 4, "ties": 0,
+                        "pointsFor": 525, "pointsAgainst": 368,
+                        "streak": "W2", "divisionWins": 5, "divisionLosses": 1
+                    }
+                },
+                "conferenceRank": { "conferenceName": "AFC", "rank": 2 },
+                "divisionRank": { "divisionName": "AFC East", "rank": 1 },
+                "playoffRank": { "conferenceName": "AFC", "appliesTo": "AFC", "rank": 2 }
+            }, {
+                "team": { "id": 50, "abbreviation": "MIA" },
+                "stats": { "standings": { "wins": 8, "losses": 9, "ties": 0 } },
+                "conferenceRank": { "conferenceName": "AFC", "rank": 9 },
+                "divisionRank": { "divisionName": "AFC East", "rank": 2 },
+                "playoffRank": { "conferenceName": "AFC", "appliesTo": "AFC", "rank": 9 }
+            }]
+        }"#;
+
+        let response: StandingsResponse = serde_json::from_str(json).unwrap();
+        let standings = Standings {
+            teams: response
+                .teams
+                .iter()
+                .map(|entry| TeamStanding::from_parsed(entry, Standings::PLAYOFF_SPOTS))
+                .collect(),
+            season: "2024-regular".to_string(),
+        };
+
+        let bills = standings.team("buf").unwrap();
+        assert_eq!(bills.record.to_string(), "13-4-0");
+        assert_eq!(bills.streak, Some(Streak::Won(2)));
+        assert_eq!(bills.division_record.unwrap().to_string(), "5-1-0");
+        assert!(bills.conference_record.is_none());
+        assert_eq!(bills.seed, Some(2));
+
+        let dolphins = standings.team("MIA").unwrap();
+        assert_eq!(dolphins.seed, None);
+        assert!(dolphins.streak.is_none());
+
+        let east = standings.division("afc east");
+        assert_eq!(east[0].abbreviation, "BUF");
+        assert_eq!(standings.playoff_picture("AFC").len(), 1);
+    }
+
+    #[test]
+    fn test_streak_from_parsed() {
+        assert_eq!(Streak::from_parsed("L3"), Some(Streak::Lost(3)));
+        assert_eq!(Streak::from_parsed("t1"), Some(Streak::Tied(1)));
+        assert_eq!(Streak::from_parsed("W"), None);
+        assert_eq!(Streak::from_parsed(""), None);
+        assert_eq!(Streak::Won(4).to_string(), "W4");
+    }
+}
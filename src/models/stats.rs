@@ -0,0 +1,613 @@
+use crate::models::parsers::player_parser::{
+    FumbleStats, InterceptionStats, KickOffReturns, MiscellaneousStats, PassingStats, PuntReturns,
+    ReceivingStats, RushingStats, SnapCounts, TackleStats, TwoPointAttempts,
+};
+
+/// Season passing totals for a player.
+///
+/// Only attached to `PlayerStats` when the player has at least one
+/// pass attempt for the requested season.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PassingLine {
+    /// Pass attempts
+    pub attempts: i64,
+    /// Completed passes
+    pub completions: i64,
+    /// Completion percentage (0-100)
+    pub completion_pct: f64,
+    /// Total passing yards
+    pub yards: i64,
+    /// Average yards per completion
+    pub yards_per_completion: f64,
+    /// Average yards per attempt
+    pub yards_per_attempt: f64,
+    /// Passing touchdowns
+    pub touchdowns: i64,
+    /// Percentage of attempts resulting in a touchdown
+    pub touchdown_pct: f64,
+    /// Interceptions thrown
+    pub interceptions: i64,
+    /// Percentage of attempts resulting in an interception
+    pub interception_pct: f64,
+    /// Longest completion in yards
+    pub longest: i64,
+    /// Completions of 20 or more yards
+    pub twenty_plus: i64,
+    /// Completions of 40 or more yards
+    pub forty_plus: i64,
+    /// Times sacked
+    pub sacks: i64,
+    /// Yards lost to sacks
+    pub sack_yards: i64,
+    /// NFL passer rating
+    pub quarterback_rating: f64,
+}
+
+impl PassingLine {
+    pub(crate) fn from_parsed(stats: Option<&PassingStats>) -> Option<Self> {
+        let stats = stats?;
+        let line = Self {
+            attempts: stats.attempts.unwrap_or(0),
+            completions: stats.completions.unwrap_or(0),
+            completion_pct: stats.percentage.unwrap_or(0.0),
+            yards: stats.yards_total.unwrap_or(0),
+            yards_per_completion: stats.yards_average.unwrap_or(0.0),
+            yards_per_attempt: stats.yards_per_attempt.unwrap_or(0.0),
+            touchdowns: stats.touchdowns.unwrap_or(0),
+            touchdown_pct: stats.touchdowns_percentage.unwrap_or(0.0),
+            interceptions: stats.interceptions.unwrap_or(0),
+            interception_pct: stats.interception_percentage.unwrap_or(0.0),
+            longest: stats.longest_pass.unwrap_or(0),
+            twenty_plus: stats.twenty_plus_yards.unwrap_or(0),
+            forty_plus: stats.forty_plus_yards.unwrap_or(0),
+            sacks: stats.times_sacked.unwrap_or(0),
+            sack_yards: stats.sack_yards_lost.unwrap_or(0),
+            quarterback_rating: stats.quarterback_rating.unwrap_or(0.0),
+        };
+
+        (line.attempts > 0).then_some(line)
+    }
+}
+
+/// Season rushing totals for a player.
+///
+/// Only attached to `PlayerStats` when the player has at least one carry.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RushingLine {
+    /// Rushing attempts
+    pub attempts: i64,
+    /// Total rushing yards
+    pub yards: i64,
+    /// Average yards per carry
+    pub yards_per_carry: f64,
+    /// Rushing touchdowns
+    pub touchdowns: i64,
+    /// Longest rush in yards
+    pub longest: i64,
+    /// Rushes resulting in a first down
+    pub first_downs: i64,
+    /// Percentage of rushes resulting in a first down (0-100)
+    pub first_down_pct: f64,
+    /// Rushes of 20 or more yards
+    pub twenty_plus: i64,
+    /// Rushes of 40 or more yards
+    pub forty_plus: i64,
+    /// Fumbles on rushing plays
+    pub fumbles: i64,
+}
+
+impl RushingLine {
+    /// Share of carries that went for a first down (0.0-1.0).
+    pub fn first_down_rate(&self) -> Option<f64> {
+        ratio(self.first_downs, self.attempts)
+    }
+
+    /// Fumbles per carry (0.0-1.0).
+    pub fn fumble_rate(&self) -> Option<f64> {
+        ratio(self.fumbles, self.attempts)
+    }
+
+    pub(crate) fn from_parsed(stats: Option<&RushingStats>) -> Option<Self> {
+        let stats = stats?;
+        let line = Self {
+            attempts: stats.attempts.unwrap_or(0),
+            yards: stats.yards_total.unwrap_or(0),
+            yards_per_carry: stats.yards_average.unwrap_or(0.0),
+            touchdowns: stats.touchdowns.unwrap_or(0),
+            longest: stats.longest_rush_yards.unwrap_or(0),
+            first_downs: stats.first_downs.unwrap_or(0),
+            first_down_pct: stats.first_downs_percentage.unwrap_or(0.0),
+            twenty_plus: stats.twenty_plus_yards.unwrap_or(0),
+            forty_plus: stats.forty_plus_yards.unwrap_or(0),
+            fumbles: stats.fumbles.unwrap_or(0),
+        };
+
+        (line.attempts > 0).then_some(line)
+    }
+}
+
+/// Season receiving totals for a player.
+///
+/// Only attached to `PlayerStats` when the player was targeted at least once.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReceivingLine {
+    /// Times targeted
+    pub targets: i64,
+    /// Receptions
+    pub receptions: i64,
+    /// Total receiving yards
+    pub yards: i64,
+    /// Average yards per reception
+    pub yards_per_reception: f64,
+    /// Receiving touchdowns
+    pub touchdowns: i64,
+    /// Longest reception in yards
+    pub longest: i64,
+    /// Receptions resulting in a first down
+    pub first_downs: i64,
+    /// Receptions of 20 or more yards
+    pub twenty_plus: i64,
+    /// Receptions of 40 or more yards
+    pub forty_plus: i64,
+    /// Fumbles after a reception
+    pub fumbles: i64,
+}
+
+impl ReceivingLine {
+    /// Receptions per target (0.0-1.0).
+    pub fn catch_rate(&self) -> Option<f64> {
+        ratio(self.receptions, self.targets)
+    }
+
+    /// Receiving yards per target.
+    pub fn yards_per_target(&self) -> Option<f64> {
+        ratio(self.yards, self.targets)
+    }
+
+    /// Share of receptions that went for a first down (0.0-1.0).
+    pub fn first_down_rate(&self) -> Option<f64> {
+        ratio(self.first_downs, self.receptions)
+    }
+
+    /// Fumbles per reception (0.0-1.0).
+    pub fn fumble_rate(&self) -> Option<f64> {
+        ratio(self.fumbles, self.receptions)
+    }
+
+    pub(crate) fn from_parsed(stats: Option<&ReceivingStats>) -> Option<Self> {
+        let stats = stats?;
+        let line = Self {
+            targets: stats.targets.unwrap_or(0),
+            receptions: stats.receptions.unwrap_or(0),
+            yards: stats.yards_total.unwrap_or(0),
+            yards_per_reception: stats.yards_average.unwrap_or(0.0),
+            touchdowns: stats.touchdowns.unwrap_or(0),
+            longest: stats.longest_reception_yards.unwrap_or(0),
+            first_downs: stats.first_downs.unwrap_or(0),
+            twenty_plus: stats.twenty_plus_yards.unwrap_or(0),
+            forty_plus: stats.forty_plus_yards.unwrap_or(0),
+            fumbles: stats.fumbles.unwrap_or(0),
+        };
+
+        (line.targets > 0 || line.receptions > 0).then_some(line)
+    }
+}
+
+/// Season defensive totals for a player, as used by IDP scoring.
+///
+/// Only attached to `PlayerStats` when the player recorded at least one
+/// defensive stat.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DefensiveLine {
+    /// Solo tackles
+    pub solo_tackles: i64,
+    /// Assisted tackles
+    pub assisted_tackles: i64,
+    /// Total tackles (solo + assisted)
+    pub total_tackles: i64,
+    /// Tackles for a loss
+    pub tackles_for_loss: i64,
+    /// Sacks (half sacks are possible)
+    pub sacks: f64,
+    /// Yards lost by the offense on this player's sacks
+    pub sack_yards: i64,
+    /// Passes defended
+    pub passes_defended: i64,
+    /// Interceptions
+    pub interceptions: i64,
+    /// Interception return yards
+    pub interception_yards: i64,
+    /// Fumbles forced
+    pub forced_fumbles: i64,
+    /// Opponent fumbles recovered
+    pub fumble_recoveries: i64,
+    /// Touchdowns scored on interception and fumble returns
+    pub defensive_touchdowns: i64,
+    /// Safeties
+    pub safeties: i64,
+}
+
+impl DefensiveLine {
+    pub(crate) fn from_parsed(
+        tackles: Option<&TackleStats>,
+        interceptions: Option<&InterceptionStats>,
+        fumbles: Option<&FumbleStats>,
+    ) -> Option<Self> {
+        if tackles.is_none() && interceptions.is_none() && fumbles.is_none() {
+            return None;
+        }
+
+        let mut line = Self::default();
+
+        if let Some(tackles) = tackles {
+            line.solo_tackles = tackles.tackles.unwrap_or(0);
+            line.assisted_tackles = tackles.assisted_tackles.unwrap_or(0);
+            line.total_tackles = tackles
+                .tackles_total
+                .unwrap_or(line.solo_tackles + line.assisted_tackles);
+            line.tackles_for_loss = tackles.tackles_for_loss.unwrap_or(0);
+            line.sacks = tackles.sacks.unwrap_or(0.0);
+            line.sack_yards = tackles.sack_yards_lost.unwrap_or(0);
+        }
+
+        if let Some(interceptions) = interceptions {
+            line.passes_defended = interceptions.passes_defended.unwrap_or(0);
+            line.interceptions = interceptions.interceptions.unwrap_or(0);
+            line.interception_yards = interceptions.yards_total.unwrap_or(0);
+            line.defensive_touchdowns += interceptions.touchdowns.unwrap_or(0);
+            line.safeties = interceptions.safeties_scored.unwrap_or(0);
+        }
+
+        if let Some(fumbles) = fumbles {
+            line.forced_fumbles = fumbles.forced.unwrap_or(0);
+            line.fumble_recoveries = fumbles.opponent_recovered.unwrap_or(0);
+            line.defensive_touchdowns += fumbles.recovery_touchdowns.unwrap_or(0);
+        }
+
+        let recorded = line.total_tackles > 0
+            || line.sacks > 0.0
+            || line.passes_defended > 0
+            || line.interceptions > 0
+            || line.forced_fumbles > 0
+            || line.fumble_recoveries > 0
+            || line.defensive_touchdowns > 0
+            || line.safeties > 0;
+
+        recorded.then_some(line)
+    }
+}
+
+/// Season kick or punt return totals for a player.
+///
+/// The same shape is used for both kickoff and punt returns. Only attached
+/// to `PlayerStats` when the player fielded at least one kick.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReturnLine {
+    /// Kicks returned
+    pub returns: i64,
+    /// Total return yards
+    pub yards: i64,
+    /// Average yards per return
+    pub yards_per_return: f64,
+    /// Longest return in yards
+    pub longest: i64,
+    /// Return touchdowns
+    pub touchdowns: i64,
+    /// Returns of 20 or more yards
+    pub twenty_plus: i64,
+    /// Returns of 40 or more yards
+    pub forty_plus: i64,
+    /// Fair catches
+    pub fair_catches: i64,
+    /// Fumbles on returns
+    pub fumbles: i64,
+}
+
+impl ReturnLine {
+    pub(crate) fn from_kickoffs(stats: Option<&KickOffReturns>) -> Option<Self> {
+        let stats = stats?;
+        Self {
+            returns: stats.returns.unwrap_or(0),
+            yards: stats.yards_total.unwrap_or(0),
+            yards_per_return: stats.yards_average.unwrap_or(0.0),
+            longest: stats.longest_return.unwrap_or(0),
+            touchdowns: stats.touchdowns.unwrap_or(0),
+            twenty_plus: stats.twenty_plus_yards.unwrap_or(0),
+            forty_plus: stats.forty_plus_yards.unwrap_or(0),
+            fair_catches: stats.fair_catches.unwrap_or(0),
+            fumbles: stats.fumbles.unwrap_or(0),
+        }
+        .non_empty()
+    }
+
+    pub(crate) fn from_punts(stats: Option<&PuntReturns>) -> Option<Self> {
+        let stats = stats?;
+        Self {
+            returns: stats.returns.unwrap_or(0),
+            yards: stats.yards_total.unwrap_or(0),
+            yards_per_return: stats.yards_average.unwrap_or(0.0),
+            longest: stats.longest_return.unwrap_or(0),
+            touchdowns: stats.touchdowns.unwrap_or(0),
+            twenty_plus: stats.twenty_plus_yards.unwrap_or(0),
+            forty_plus: stats.forty_plus_yards.unwrap_or(0),
+            fair_catches: stats.fair_catches.unwrap_or(0),
+            fumbles: stats.fumbles.unwrap_or(0),
+        }
+        .non_empty()
+    }
+
+    fn non_empty(self) -> Option<Self> {
+        (self.returns > 0 || self.fair_catches > 0).then_some(self)
+    }
+}
+
+/// Season two-point conversion totals for a player.
+///
+/// Only attached to `PlayerStats` when the player was involved in at least
+/// one two-point try.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TwoPointLine {
+    /// Two-point tries involving the player
+    pub attempts: i64,
+    /// Successful two-point tries
+    pub conversions: i64,
+    /// Two-point tries thrown
+    pub pass_attempts: i64,
+    /// Successful two-point tries thrown
+    pub pass_conversions: i64,
+    /// Two-point passes caught
+    pub receptions: i64,
+    /// Two-point tries rushed
+    pub rush_attempts: i64,
+    /// Successful two-point tries rushed
+    pub rush_conversions: i64,
+}
+
+impl TwoPointLine {
+    /// Successful tries per attempt (0.0-1.0).
+    pub fn conversion_rate(&self) -> Option<f64> {
+        ratio(self.conversions, self.attempts)
+    }
+
+    pub(crate) fn from_parsed(stats: Option<&TwoPointAttempts>) -> Option<Self> {
+        let stats = stats?;
+        let line = Self {
+            attempts: stats.two_point_attempts_total.unwrap_or(0),
+            conversions: stats.two_point_conversions_made.unwrap_or(0),
+            pass_attempts: stats.two_point_pass_attempts.unwrap_or(0),
+            pass_conversions: stats.two_point_pass_conversions.unwrap_or(0),
+            receptions: stats.two_point_pass_receptions.unwrap_or(0),
+            rush_attempts: stats.two_point_rush_attempts.unwrap_or(0),
+            rush_conversions: stats.two_point_rush_conversions.unwrap_or(0),
+        };
+
+        (line.attempts > 0 || line.receptions > 0).then_some(line)
+    }
+}
+
+/// Season snap counts and usage for a player.
+///
+/// Team snap totals are not part of the player feed, so snap share is
+/// computed against totals supplied by the caller.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SnapUsage {
+    /// Offensive snaps played
+    pub offense_snaps: i64,
+    /// Defensive snaps played
+    pub defense_snaps: i64,
+    /// Special teams snaps played
+    pub special_teams_snaps: i64,
+    /// Games played
+    pub games_played: i64,
+    /// Games started
+    pub games_started: i64,
+}
+
+impl SnapUsage {
+    /// Total snaps across offense, defense and special teams.
+    pub fn total_snaps(&self) -> i64 {
+        self.offense_snaps + self.defense_snaps + self.special_teams_snaps
+    }
+
+    /// Average snaps per game played.
+    pub fn snaps_per_game(&self) -> Option<f64> {
+        ratio(self.total_snaps(), self.games_played)
+    }
+
+    /// Share of games played that the player started (0.0-1.0).
+    pub fn games_started_ratio(&self) -> Option<f64> {
+        ratio(self.games_started, self.games_played)
+    }
+
+    /// Share of the team's offensive snaps the player was on the field for, as a percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_offense_snaps` - Total offensive snaps run by the team over the same games
+    pub fn offense_snap_share(&self, team_offense_snaps: i64) -> Option<f64> {
+        ratio(self.offense_snaps, team_offense_snaps).map(|share| share * 100.0)
+    }
+
+    /// Share of the team's defensive snaps the player was on the field for, as a percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_defense_snaps` - Total defensive snaps played by the team over the same games
+    pub fn defense_snap_share(&self, team_defense_snaps: i64) -> Option<f64> {
+        ratio(self.defense_snaps, team_defense_snaps).map(|share| share * 100.0)
+    }
+
+    pub(crate) fn from_parsed(
+        snaps: Option<&SnapCounts>,
+        miscellaneous: Option<&MiscellaneousStats>,
+        games_played: u64,
+    ) -> Option<Self> {
+        if snaps.is_none() && miscellaneous.is_none() {
+            return None;
+        }
+
+        let usage = Self {
+            offense_snaps: snaps.and_then(|s| s.offense_snaps).unwrap_or(0),
+            defense_snaps: snaps.and_then(|s| s.defense_snaps).unwrap_or(0),
+            special_teams_snaps: snaps.and_then(|s| s.special_team_snaps).unwrap_or(0),
+            games_played: games_played as i64,
+            games_started: miscellaneous.and_then(|m| m.games_started).unwrap_or(0),
+        };
+
+        (usage.total_snaps() > 0 || usage.games_started > 0).then_some(usage)
+    }
+}
+
+/// Divides two counting stats, returning `None` when the denominator is zero.
+fn ratio(numerator: i64, denominator: i64) -> Option<f64> {
+    (denominator > 0).then(|| numerator as f64 / denominator as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passing_line_requires_attempts() {
+        assert_eq!(PassingLine::from_parsed(None), None);
+
+        let empty = PassingStats::default();
+        assert_eq!(PassingLine::from_parsed(Some(&empty)), None);
+
+        let parsed = PassingStats {
+            attempts: Some(541),
+            completions: Some(359),
+            yards_total: Some(4306),
+            touchdowns: Some(29),
+            ..Default::default()
+        };
+        let line = PassingLine::from_parsed(Some(&parsed)).unwrap();
+        assert_eq!(line.yards, 4306);
+        assert_eq!(line.touchdowns, 29);
+        assert_eq!(line.interceptions, 0);
+    }
+
+    #[test]
+    fn test_receiving_derived_metrics() {
+        let line = ReceivingLine {
+            targets: 100,
+            receptions: 75,
+            yards: 900,
+            first_downs: 45,
+            fumbles: 3,
+            ..Default::default()
+        };
+
+        assert_eq!(line.catch_rate(), Some(0.75));
+        assert_eq!(line.yards_per_target(), Some(9.0));
+        assert_eq!(line.first_down_rate(), Some(0.6));
+        assert_eq!(line.fumble_rate(), Some(0.04));
+        assert_eq!(ReceivingLine::default().catch_rate(), None);
+    }
+
+    #[test]
+    fn test_rushing_derived_metrics() {
+        let line = RushingLine {
+            attempts: 200,
+            first_downs: 50,
+            fumbles: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(line.first_down_rate(), Some(0.25));
+        assert_eq!(line.fumble_rate(), Some(0.01));
+        assert_eq!(
+            RushingLine::from_parsed(Some(&RushingStats::default())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_defensive_line_combines_blocks() {
+        let tackles = TackleStats {
+            tackles: Some(80),
+            assisted_tackles: Some(30),
+            sacks: Some(4.5),
+            ..Default::default()
+        };
+        let interceptions = InterceptionStats {
+            interceptions: Some(2),
+            touchdowns: Some(1),
+            ..Default::default()
+        };
+        let fumbles = FumbleStats {
+            forced: Some(3),
+            recovery_touchdowns: Some(1),
+            ..Default::default()
+        };
+
+        let line = DefensiveLine::from_parsed(Some(&tackles), Some(&interceptions), Some(&fumbles))
+            .unwrap();
+        assert_eq!(line.total_tackles, 110);
+        assert_eq!(line.sacks, 4.5);
+        assert_eq!(line.defensive_touchdowns, 2);
+        assert_eq!(line.forced_fumbles, 3);
+
+        // Offensive players still get an (empty) fumbles block from the feed
+        let offensive_fumbles = FumbleStats {
+            fumbles: Some(2),
+            lost: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            DefensiveLine::from_parsed(None, None, Some(&offensive_fumbles)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_return_lines() {
+        let punts = PuntReturns {
+            returns: Some(20),
+            yards_total: Some(210),
+            fair_catches: Some(12),
+            ..Default::default()
+        };
+        let line = ReturnLine::from_punts(Some(&punts)).unwrap();
+        assert_eq!(line.returns, 20);
+        assert_eq!(line.fair_catches, 12);
+
+        assert_eq!(
+            ReturnLine::from_kickoffs(Some(&KickOffReturns::default())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_two_point_conversion_rate() {
+        let parsed = TwoPointAttempts {
+            two_point_attempts_total: Some(4),
+            two_point_conversions_made: Some(3),
+            ..Default::default()
+        };
+        let line = TwoPointLine::from_parsed(Some(&parsed)).unwrap();
+        assert_eq!(line.conversion_rate(), Some(0.75));
+    }
+
+    #[test]
+    fn test_snap_usage_metrics() {
+        let snaps = SnapCounts {
+            offense_snaps: Some(900),
+            special_team_snaps: Some(20),
+            ..Default::default()
+        };
+        let miscellaneous = MiscellaneousStats {
+            games_started: Some(12),
+        };
+
+        let usage = SnapUsage::from_parsed(Some(&snaps), Some(&miscellaneous), 16).unwrap();
+        assert_eq!(usage.total_snaps(), 920);
+        assert_eq!(usage.snaps_per_game(), Some(57.5));
+        assert_eq!(usage.games_started_ratio(), Some(0.75));
+        assert_eq!(usage.offense_snap_share(1000), Some(90.0));
+        assert_eq!(usage.defense_snap_share(1000), Some(0.0));
+        assert_eq!(usage.offense_snap_share(0), None);
+
+        assert_eq!(SnapUsage::from_parsed(None, None, 16), None);
+    }
+}
@@ -0,0 +1,254 @@
+use crate::models::parsers::team_parser::{StandingsStats, TeamStatistics, TeamStatsEntry};
+
+/// A win-loss-tie record.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Record {
+    /// Games won
+    pub wins: u32,
+    /// Games lost
+    pub losses: u32,
+    /// Games tied
+    pub ties: u32,
+}
+
+impl Record {
+    /// Total games in the record.
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.ties
+    }
+
+    /// Win percentage (0.0-1.0), counting ties as half a win.
+    pub fn win_pct(&self) -> Option<f64> {
+        let games = self.games();
+        (games > 0).then(|| (self.wins as f64 + self.ties as f64 / 2.0) / games as f64)
+    }
+
+    pub(crate) fn from_parsed(standings: &StandingsStats) -> Self {
+        Self {
+            wins: standings.wins.unwrap_or(0),
+            losses: standings.losses.unwrap_or(0),
+            ties: standings.ties.unwrap_or(0),
+        }
+    }
+}
+
+impl std::fmt::Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}-{}", self.wins, self.losses, self.ties)
+    }
+}
+
+/// Offensive totals for a team over a season or a single game.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TeamOffense {
+    /// Offensive plays run
+    pub plays: i64,
+    /// Total offensive yards
+    pub total_yards: i64,
+    /// First downs gained
+    pub first_downs: i64,
+    /// Pass attempts
+    pub pass_attempts: i64,
+    /// Completed passes
+    pub completions: i64,
+    /// Passing yards net of sack yardage
+    pub passing_yards: i64,
+    /// Passing touchdowns
+    pub passing_touchdowns: i64,
+    /// Interceptions thrown
+    pub interceptions_thrown: i64,
+    /// Sacks allowed
+    pub sacks_allowed: i64,
+    /// Rushing attempts
+    pub rush_attempts: i64,
+    /// Rushing yards
+    pub rushing_yards: i64,
+    /// Rushing touchdowns
+    pub rushing_touchdowns: i64,
+    /// Fumbles lost
+    pub fumbles_lost: i64,
+}
+
+impl TeamOffense {
+    pub(crate) fn from_parsed(statistics: &TeamStatistics) -> Self {
+        let passing = statistics.passing_stats.as_ref();
+        let rushing = statistics.rushing_stats.as_ref();
+        let fumbles = statistics.fumbles_stats.as_ref();
+        let miscellaneous = statistics.miscellaneous_stats.as_ref();
+
+        Self {
+            plays: miscellaneous.and_then(|m| m.offense_plays).unwrap_or(0),
+            total_yards: miscellaneous.and_then(|m| m.offense_yards).unwrap_or(0),
+            first_downs: miscellaneous.and_then(|m| m.first_downs).unwrap_or(0),
+            pass_attempts: passing.and_then(|p| p.attempts).unwrap_or(0),
+            completions: passing.and_then(|p| p.completions).unwrap_or(0),
+            passing_yards: passing
+                .and_then(|p| p.net_yards.or(p.yards_total))
+                .unwrap_or(0),
+            passing_touchdowns: passing.and_then(|p| p.touchdowns).unwrap_or(0),
+            interceptions_thrown: passing.and_then(|p| p.interceptions).unwrap_or(0),
+            sacks_allowed: passing.and_then(|p| p.times_sacked).unwrap_or(0),
+            rush_attempts: rushing.and_then(|r| r.attempts).unwrap_or(0),
+            rushing_yards: rushing.and_then(|r| r.yards_total).unwrap_or(0),
+            rushing_touchdowns: rushing.and_then(|r| r.touchdowns).unwrap_or(0),
+            fumbles_lost: fumbles.and_then(|f| f.lost).unwrap_or(0),
+        }
+    }
+}
+
+/// Defensive totals for a team over a season or a single game.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TeamDefense {
+    /// Sacks
+    pub sacks: f64,
+    /// Tackles for a loss
+    pub tackles_for_loss: i64,
+    /// Interceptions
+    pub interceptions: i64,
+    /// Passes defended
+    pub passes_defended: i64,
+    /// Fumbles forced
+    pub forced_fumbles: i64,
+    /// Opponent fumbles recovered
+    pub fumble_recoveries: i64,
+    /// Touchdowns scored on interception and fumble returns
+    pub defensive_touchdowns: i64,
+    /// Safeties
+    pub safeties: i64,
+}
+
+impl TeamDefense {
+    pub(crate) fn from_parsed(statistics: &TeamStatistics) -> Self {
+        let tackles = statistics.tackles_stats.as_ref();
+        let interceptions = statistics.interceptions_stats.as_ref();
+        let fumbles = statistics.fumbles_stats.as_ref();
+
+        Self {
+            sacks: tackles.and_then(|t| t.sacks).unwrap_or(0.0),
+            tackles_for_loss: tackles.and_then(|t| t.tackles_for_loss).unwrap_or(0),
+            interceptions: interceptions.and_then(|i| i.interceptions).unwrap_or(0),
+            passes_defended: interceptions.and_then(|i| i.passes_defended).unwrap_or(0),
+            forced_fumbles: fumbles.and_then(|f| f.forced).unwrap_or(0),
+            fumble_recoveries: fumbles.and_then(|f| f.opponent_recovered).unwrap_or(0),
+            defensive_touchdowns: interceptions.and_then(|i| i.touchdowns).unwrap_or(0)
+                + fumbles.and_then(|f| f.recovery_touchdowns).unwrap_or(0),
+            safeties: interceptions.and_then(|i| i.safeties_scored).unwrap_or(0),
+        }
+    }
+}
+
+/// Season statistics for a team.
+///
+/// This struct contains the team's record, scoring and offensive and
+/// defensive totals for the requested season.
+
+#[derive(Debug, Clone, Default)]
+pub struct TeamStats {
+    /// Provider's numeric team ID
+    pub team_id: u64,
+    /// Team abbreviation (e.g., "BUF")
+    pub abbreviation: String,
+    /// Team city (e.g., "Buffalo")
+    pub city: String,
+    /// Team name (e.g., "Bills")
+    pub name: String,
+    /// Total games played this season
+    pub games_played: u64,
+    /// Win-loss-tie record
+    pub record: Record,
+    /// Points scored
+    pub points_for: i64,
+    /// Points allowed
+    pub points_against: i64,
+    /// Offensive totals
+    pub offense: TeamOffense,
+    /// Defensive totals
+    pub defense: TeamDefense,
+    /// Season for which these stats apply
+    pub season: String,
+}
+
+impl TeamStats {
+    /// Points scored minus points allowed.
+    pub fn point_differential(&self) -> i64 {
+        self.points_for - self.points_against
+    }
+
+    /// Average points scored per game.
+    pub fn points_per_game(&self) -> Option<f64> {
+        (self.games_played > 0).then(|| self.points_for as f64 / self.games_played as f64)
+    }
+
+    /// Average points allowed per game.
+    pub fn points_allowed_per_game(&self) -> Option<f64> {
+        (self.games_played > 0).then(|| self.points_against as f64 / self.games_played as f64)
+    }
+
+    pub(crate) fn from_parsed(entry: &TeamStatsEntry, season: &str) -> Self {
+        let team = &entry.team_info;
+        let statistics = &entry.statistics;
+        let standings = statistics.standings.as_ref();
+
+        Self {
+            team_id: team.id.unwrap_or(0),
+            abbreviation: team.abbreviation.clone().unwrap_or_default(),
+            city: team.city.clone().unwrap_or_default(),
+            name: team.name.clone().unwrap_or_default(),
+            games_played: statistics.games_played.unwrap_or(0),
+            record: standings.map(Record::from_parsed).unwrap_or_default(),
+            points_for: standings.and_then(|s| s.points_for).unwrap_or(0),
+            points_against: standings.and_then(|s| s.points_against).unwrap_or(0),
+            offense: TeamOffense::from_parsed(statistics),
+            defense: TeamDefense::from_parsed(statistics),
+            season: season.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parsers::team_parser::TeamStatsResponse;
+
+    #[test]
+    fn test_team_stats_from_parsed() {
+        let json = r#"{
+            "teamStatsTotals": [{
+                "team": { "id": 48, "abbreviation": "BUF", "city": "Buffalo", "name": "Bills" },
+                "stats": {
+                    "gamesPlayed": 17,
+                    "passing": { "passAttempts": 520, "passYards": 4100, "passNetYards": 3950 },
+                    "rushing": { "rushAttempts": 480, "rushYards": 2200, "rushTD": 31 },
+                    "tackles": { "sacks": 40.5 },
+                    "interceptions": { "interceptions": 16, "intTD": 2 },
+                    "fumbles": { "fumForced": 12, "fumOppRec": 8, "fumTD": 1 },
+                    "standings": { "wins": 13, "losses": 4, "ties": 0, "pointsFor": 525, "pointsAgainst": 368 }
+                }
+            }]
+        }"#;
+
+        let response: TeamStatsResponse = serde_json::from_str(json).unwrap();
+        let stats = TeamStats::from_parsed(&response.teams[0], "2024-regular");
+
+        assert_eq!(stats.abbreviation, "BUF");
+        assert_eq!(stats.record.to_string(), "13-4-0");
+        assert_eq!(stats.point_differential(), 157);
+        assert_eq!(stats.offense.passing_yards, 3950);
+        assert_eq!(stats.offense.rushing_touchdowns, 31);
+        assert_eq!(stats.defense.sacks, 40.5);
+        assert_eq!(stats.defense.defensive_touchdowns, 3);
+        assert_eq!(stats.defense.fumble_recoveries, 8);
+    }
+
+    #[test]
+    fn test_record_win_pct_counts_ties_as_half() {
+        let record = Record {
+            wins: 8,
+            losses: 8,
+            ties: 1,
+        };
+        assert_eq!(record.games(), 17);
+        assert_eq!(record.win_pct(), Some(8.5 / 17.0));
+        assert_eq!(Record::default().win_pct(), None);
+    }
+}
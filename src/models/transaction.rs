@@ -0,0 +1,168 @@
+use crate::models::{
+    parsers::player_parser::{TeamInfo, TransactionEntry},
+    player::PlayerCandidate,
+};
+use chrono::NaiveDate;
+
+/// The kind of roster move a transaction records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionKind {
+    /// Signed to the active roster
+    Signing,
+    /// Released or waived
+    Release,
+    /// Traded to another team
+    Trade,
+    /// Placed on injured reserve
+    InjuredReserve,
+    /// Activated from injured reserve or another reserve list
+    Activation,
+    /// Signed to, released from or elevated from the practice squad
+    PracticeSquad,
+    /// Any other transaction type reported by the provider
+    Other(String),
+}
+
+impl TransactionKind {
+    pub(crate) fn from_parsed(transaction_type: &str) -> Self {
+        let normalized = transaction_type.to_uppercase();
+
+        if normalized.contains("PRACTICE") {
+            TransactionKind::PracticeSquad
+        } else if normalized.contains("ACTIVATE") {
+            TransactionKind::Activation
+        } else if normalized.contains("INJURED_RESERVE") || normalized == "IR" {
+            TransactionKind::InjuredReserve
+        } else if normalized.contains("TRADE") {
+            TransactionKind::Trade
+        } else if normalized.contains("RELEASE") || normalized.contains("WAIVE") {
+            TransactionKind::Release
+        } else if normalized.contains("SIGN") {
+            TransactionKind::Signing
+        } else {
+            TransactionKind::Other(transaction_type.to_string())
+        }
+    }
+}
+
+/// A single roster move.
+
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    /// Date the transaction took effect
+    pub date: NaiveDate,
+    /// Kind of move
+    pub kind: TransactionKind,
+    /// The player involved
+    pub player: PlayerCandidate,
+    /// Team the player left, if any
+    pub from_team: Option<String>,
+    /// Team the player joined, if any
+    pub to_team: Option<String>,
+    /// Player's roster status after the move (e.g., "ROSTER", "INJURED_RESERVE")
+    pub roster_status: String,
+    /// Provider's text description of the move
+    pub description: String,
+}
+
+impl Transaction {
+    /// Returns true if the given team is on either side of the move.
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - Team abbreviation (e.g., "BUF"), compared case-insensitively
+    pub fn involves(&self, team: &str) -> bool {
+        [&self.from_team, &self.to_team]
+            .into_iter()
+            .flatten()
+            .any(|abbreviation| abbreviation.eq_ignore_ascii_case(team))
+    }
+
+    /// Builds a transaction, or `None` if the entry has no parsable date.
+    pub(crate) fn from_parsed(entry: &TransactionEntry) -> Option<Self> {
+        let date = NaiveDate::parse_from_str(entry.as_of_date.as_deref()?, "%Y-%m-%d").ok()?;
+        let abbreviation = |team: Option<&TeamInfo>| team.and_then(|t| t.abbreviation.clone());
+
+        Some(Self {
+            date,
+            kind: TransactionKind::from_parsed(
+                entry.transaction_type.as_deref().unwrap_or_default(),
+            ),
+            player: PlayerCandidate::from_parsed(&entry.player_info),
+            from_team: abbreviation(entry.from_team.as_ref()),
+            to_team: abbreviation(entry.to_team.as_ref()),
+            roster_status: entry.player_info.roster_status.clone().unwrap_or_default(),
+            description: entry.description.clone().unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::parsers::player_parser::TransactionsResponse;
+
+    #[test]
+    fn test_transaction_from_parsed() {
+        let json = r#"{
+            "transactions": [{
+                "asOfDate": "2024-10-15",
+                "type": "TRADE",
+                "description": "Buffalo Bills acquired WR Amari Cooper from Cleveland Browns",
+                "player": { "id": 6744, "firstName": "Amari", "lastName": "Cooper", "currentRosterStatus": "ROSTER" },
+                "fromTeam": { "abbreviation": "CLE" },
+                "toTeam": { "abbreviation": "BUF" }
+            }, {
+                "asOfDate": "2024-09-10",
+                "type": "PRACTICE_SQUAD_SIGNING",
+                "player": { "id": 21500 },
+                "toTeam": { "abbreviation": "BUF" }
+            }, {
+                "type": "RELEASE",
+                "player": { "id": 1 }
+            }]
+        }"#;
+
+        let response: TransactionsResponse = serde_json::from_str(json).unwrap();
+        let transactions: Vec<Transaction> = response
+            .transactions
+            .iter()
+            .filter_map(Transaction::from_parsed)
+            .collect();
+
+        assert_eq!(transactions.len(), 2);
+        let trade = &transactions[0];
+        assert_eq!(trade.kind, TransactionKind::Trade);
+        assert_eq!(trade.date, NaiveDate::from_ymd_opt(2024, 10, 15).unwrap());
+        assert_eq!(trade.player.last_name, "Cooper");
+        assert!(trade.involves("cle") && trade.involves("BUF"));
+        assert_eq!(trade.roster_status, "ROSTER");
+
+        assert_eq!(transactions[1].kind, TransactionKind::PracticeSquad);
+        assert!(transactions[1].from_team.is_none());
+    }
+
+    #[test]
+    fn test_transaction_kind_from_parsed() {
+        assert_eq!(
+            TransactionKind::from_parsed("SIGNING"),
+            TransactionKind::Signing
+        );
+        assert_eq!(
+            TransactionKind::from_parsed("waived"),
+            TransactionKind::Release
+        );
+        assert_eq!(
+            TransactionKind::from_parsed("PLACED_ON_INJURED_RESERVE"),
+            TransactionKind::InjuredReserve
+        );
+        assert_eq!(
+            TransactionKind::from_parsed("ACTIVATED_FROM_INJURED_RESERVE"),
+            TransactionKind::Activation
+        );
+        assert_eq!(
+            TransactionKind::from_parsed("SUSPENSION"),
+            TransactionKind::Other("SUSPENSION".to_string())
+        );
+    }
+}
@@ -1,14 +1,35 @@
 use crate::{
     error::{Result, StatbookError},
-    models::{Article, NewsQuery, PlayerNews, PlayerStats},
+    models::{
+        Article, Boxscore, BoxscorePlayer, DepthChart, DepthChartEntry, FieldPosition, Game,
+        GameLine, GameLog, GameStatus, HomeAway, InjuredPlayer, Injury, InjuryStatus, Moneyline,
+        NewsQuery, PassingLine, Play, PlayParticipant, PlayRole, PlayType, PlayerCandidate,
+        PlayerHint, PlayerNews, PlayerProfile, PlayerProp, PlayerStats, PracticeParticipation,
+        QuarterScore, Record, RushingLine, Score, SnapUsage, Spread, Standings, Streak,
+        TeamBoxscore, TeamDefense, TeamOffense, TeamStanding, TeamStats, Total, Transaction,
+        TransactionKind,
+    },
     providers::{NewsProvider, StatsProvider},
 };
 use async_trait::async_trait;
+use chrono::{NaiveDate, TimeZone, Utc};
 use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct MockStatsProvider {
-    responses: HashMap<String, PlayerStats>,
+    responses: HashMap<String, Vec<PlayerStats>>,
+    profiles: HashMap<String, PlayerProfile>,
+    gamelogs: HashMap<String, Vec<GameLog>>,
+    teams: HashMap<String, TeamStats>,
+    standings: Vec<TeamStanding>,
+    games: Vec<Game>,
+    boxscores: HashMap<u64, Boxscore>,
+    plays: HashMap<u64, Vec<Play>>,
+    injuries: Vec<InjuredPlayer>,
+    depth_charts: HashMap<(u64, String), DepthChart>,
+    game_lines: HashMap<u64, Vec<GameLine>>,
+    player_props: HashMap<u64, Vec<PlayerProp>>,
+    transactions: Vec<Transaction>,
     errors: HashMap<String, StatbookError>,
 }
 
@@ -16,6 +37,18 @@ impl MockStatsProvider {
     pub fn new() -> Self {
         Self {
             responses: HashMap::new(),
+            profiles: HashMap::new(),
+            gamelogs: HashMap::new(),
+            teams: HashMap::new(),
+            standings: Vec::new(),
+            games: Vec::new(),
+            boxscores: HashMap::new(),
+            plays: HashMap::new(),
+            injuries: Vec::new(),
+            depth_charts: HashMap::new(),
+            game_lines: HashMap::new(),
+            player_props: HashMap::new(),
+            transactions: Vec::new(),
             errors: HashMap::new(),
         }
     }
@@ -26,38 +59,549 @@ impl MockStatsProvider {
         provider.add_player_stats(
             "josh-allen",
             PlayerStats {
+                player_id: 7549,
+                team_id: Some(48),
                 first_name: "Josh".to_string(),
                 last_name: "Allen".to_string(),
                 primary_position: "QB".to_string(),
                 jersey_number: 17,
                 current_team: "BUF".to_string(),
-                injury: "".to_string(),
+                injury: None,
                 rookie: false,
                 games_played: 16,
                 season: "2024-regular".to_string(),
+                passing: Some(PassingLine {
+                    attempts: 483,
+                    completions: 307,
+                    completion_pct: 63.6,
+                    yards: 3731,
+                    yards_per_attempt: 7.7,
+                    touchdowns: 28,
+                    interceptions: 6,
+                    quarterback_rating: 101.4,
+                    ..Default::default()
+                }),
+                rushing: Some(RushingLine {
+                    attempts: 102,
+                    yards: 531,
+                    yards_per_carry: 5.2,
+                    touchdowns: 12,
+                    first_downs: 43,
+                    fumbles: 2,
+                    ..Default::default()
+                }),
+                usage: Some(SnapUsage {
+                    offense_snaps: 1012,
+                    games_played: 16,
+                    games_started: 16,
+                    ..Default::default()
+                }),
+                ..Default::default()
             },
         );
 
         provider.add_player_stats(
             "tom-brady",
             PlayerStats {
+                player_id: 7430,
+                team_id: Some(64),
                 first_name: "Tom".to_string(),
                 last_name: "Brady".to_string(),
                 primary_position: "QB".to_string(),
                 jersey_number: 12,
                 current_team: "TB".to_string(),
-                injury: "".to_string(),
+                injury: None,
                 rookie: false,
                 games_played: 17,
                 season: "2024-regular".to_string(),
+                passing: Some(PassingLine {
+                    attempts: 733,
+                    completions: 490,
+                    completion_pct: 66.8,
+                    yards: 4694,
+                    yards_per_attempt: 6.4,
+                    touchdowns: 25,
+                    interceptions: 9,
+                    quarterback_rating: 90.7,
+                    ..Default::default()
+                }),
+                ..Default::default()
             },
         );
 
+        provider.add_player_profile(
+            "josh-allen",
+            PlayerProfile {
+                player_id: 7549,
+                first_name: "Josh".to_string(),
+                last_name: "Allen".to_string(),
+                primary_position: "QB".to_string(),
+                jersey_number: 17,
+                current_team: "BUF".to_string(),
+                roster_status: "ROSTER".to_string(),
+                rookie: false,
+                height_inches: Some(77),
+                weight: Some(237),
+                birth_date: NaiveDate::from_ymd_opt(1996, 5, 21),
+                age: Some(28),
+                birth_city: Some("Firebaugh, CA".to_string()),
+                birth_country: Some("USA".to_string()),
+                high_school: Some("Firebaugh (CA)".to_string()),
+                college: Some("Wyoming".to_string()),
+                headshot_url: None,
+            },
+        );
+
+        provider.add_player_profile(
+            "tom-brady",
+            PlayerProfile {
+                player_id: 7430,
+                first_name: "Tom".to_string(),
+                last_name: "Brady".to_string(),
+                primary_position: "QB".to_string(),
+                jersey_number: 12,
+                current_team: "TB".to_string(),
+                roster_status: "RETIRED".to_string(),
+                rookie: false,
+                height_inches: Some(76),
+                weight: Some(225),
+                birth_date: NaiveDate::from_ymd_opt(1977, 8, 3),
+                age: Some(47),
+                birth_city: Some("San Mateo, CA".to_string()),
+                birth_country: Some("USA".to_string()),
+                high_school: Some("Junipero Serra (CA)".to_string()),
+                college: Some("Michigan".to_string()),
+                headshot_url: None,
+            },
+        );
+
+        let allen_games = [
+            (1, "ARI", HomeAway::Home, 8, 17, 232, 2),
+            (2, "MIA", HomeAway::Away, 9, 12, 139, 0),
+            (3, "JAX", HomeAway::Home, 9, 23, 263, 4),
+        ];
+        provider.add_player_gamelogs(
+            "josh-allen",
+            allen_games
+                .into_iter()
+                .map(
+                    |(week, opponent, home_away, month, day, yards, touchdowns)| GameLog {
+                        game_id: 133400 + week as u64,
+                        player_id: 7549,
+                        week,
+                        start_time: Some(Utc.with_ymd_and_hms(2024, month, day, 17, 0, 0).unwrap()),
+                        team: "BUF".to_string(),
+                        opponent: opponent.to_string(),
+                        home_away,
+                        passing: Some(PassingLine {
+                            attempts: 25,
+                            yards,
+                            touchdowns,
+                            ..Default::default()
+                        }),
+                        rushing: None,
+                        receiving: None,
+                        defense: None,
+                        kick_returns: None,
+                        punt_returns: None,
+                        two_point: None,
+                        usage: None,
+                    },
+                )
+                .collect(),
+        );
+
+        provider.add_team_stats(TeamStats {
+            team_id: 48,
+            abbreviation: "BUF".to_string(),
+            city: "Buffalo".to_string(),
+            name: "Bills".to_string(),
+            games_played: 17,
+            record: Record {
+                wins: 13,
+                losses: 4,
+                ties: 0,
+            },
+            points_for: 525,
+            points_against: 368,
+            offense: TeamOffense {
+                pass_attempts: 510,
+                completions: 332,
+                passing_yards: 3870,
+                passing_touchdowns: 28,
+                rush_attempts: 494,
+                rushing_yards: 2230,
+                rushing_touchdowns: 31,
+                ..Default::default()
+            },
+            defense: TeamDefense {
+                sacks: 39.0,
+                interceptions: 16,
+                forced_fumbles: 16,
+                fumble_recoveries: 16,
+                ..Default::default()
+            },
+            season: "2024-regular".to_string(),
+        });
+
+        let bills_games = [
+            (1, "ARI", "BUF", 9, 8, GameStatus::Final, Some((34, 28))),
+            (2, "BUF", "MIA", 9, 12, GameStatus::Final, Some((10, 31))),
+            (3, "JAX", "BUF", 9, 23, GameStatus::Final, Some((47, 10))),
+            (4, "BUF", "BAL", 9, 29, GameStatus::Scheduled, None),
+        ];
+        for (week, away_team, home_team, month, day, status, score) in bills_games {
+            provider.add_game(Game {
+                game_id: 133400 + week as u64,
+                week,
+                start_time: Some(Utc.with_ymd_and_hms(2024, month, day, 17, 0, 0).unwrap()),
+                home_team: home_team.to_string(),
+                away_team: away_team.to_string(),
+                venue: None,
+                status,
+                score: score.map(|(home, away)| Score { home, away }),
+            });
+        }
+
+        let opener = provider.games[0].clone();
+        provider.add_boxscore(Boxscore {
+            game: opener,
+            quarters: [(1, 0, 14), (2, 17, 3), (3, 3, 3), (4, 14, 8)]
+                .into_iter()
+                .map(|(quarter, home, away)| QuarterScore {
+                    quarter,
+                    home,
+                    away,
+                })
+                .collect(),
+            home: TeamBoxscore {
+                team: "BUF".to_string(),
+                points: 34,
+                offense: TeamOffense {
+                    pass_attempts: 23,
+                    completions: 18,
+                    passing_yards: 224,
+                    passing_touchdowns: 2,
+                    rush_attempts: 30,
+                    rushing_yards: 155,
+                    rushing_touchdowns: 2,
+                    ..Default::default()
+                },
+                defense: TeamDefense {
+                    sacks: 2.0,
+                    ..Default::default()
+                },
+                players: vec![BoxscorePlayer {
+                    player_id: 7549,
+                    first_name: "Josh".to_string(),
+                    last_name: "Allen".to_string(),
+                    position: "QB".to_string(),
+                    jersey_number: 17,
+                    passing: Some(PassingLine {
+                        attempts: 23,
+                        completions: 18,
+                        yards: 232,
+                        touchdowns: 2,
+                        ..Default::default()
+                    }),
+                    rushing: Some(RushingLine {
+                        attempts: 9,
+                        yards: 39,
+                        touchdowns: 2,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+            },
+            away: TeamBoxscore {
+                team: "ARI".to_string(),
+                points: 28,
+                ..Default::default()
+            },
+        });
+
+        let allen = |role| PlayParticipant {
+            player_id: 7549,
+            first_name: "Josh".to_string(),
+            last_name: "Allen".to_string(),
+            role,
+        };
+        provider.add_plays(
+            133401,
+            vec![
+                Play {
+                    sequence: 0,
+                    quarter: 2,
+                    seconds_elapsed: 380,
+                    possession: "BUF".to_string(),
+                    down: Some(1),
+                    distance: Some(10),
+                    yard_line: Some(FieldPosition {
+                        side: "ARI".to_string(),
+                        yard_line: 22,
+                    }),
+                    play_type: PlayType::Rush,
+                    players: vec![allen(PlayRole::Rusher)],
+                    yards_gained: 10,
+                    scoring: false,
+                    description: "J.Allen scrambles right end for 10 yards".to_string(),
+                },
+                Play {
+                    sequence: 1,
+                    quarter: 2,
+                    seconds_elapsed: 410,
+                    possession: "BUF".to_string(),
+                    down: Some(1),
+                    distance: Some(10),
+                    yard_line: Some(FieldPosition {
+                        side: "ARI".to_string(),
+                        yard_line: 12,
+                    }),
+                    play_type: PlayType::Pass,
+                    players: vec![allen(PlayRole::Passer)],
+                    yards_gained: 12,
+                    scoring: true,
+                    description: "J.Allen pass short right for 12 yards, TOUCHDOWN".to_string(),
+                },
+            ],
+        );
+
+        let injured = [
+            (
+                19201,
+                "Dalton",
+                "Kincaid",
+                "BUF",
+                "TE",
+                "Knee",
+                InjuryStatus::Questionable,
+            ),
+            (
+                8412,
+                "Tua",
+                "Tagovailoa",
+                "MIA",
+                "QB",
+                "Concussion",
+                InjuryStatus::InjuredReserve,
+            ),
+        ];
+        for (player_id, first_name, last_name, team, position, body_part, status) in injured {
+            provider.add_injured_player(InjuredPlayer {
+                player: PlayerCandidate {
+                    player_id,
+                    first_name: first_name.to_string(),
+                    last_name: last_name.to_string(),
+                    current_team: team.to_string(),
+                    primary_position: position.to_string(),
+                },
+                injury: Injury {
+                    body_part: body_part.to_string(),
+                    practice: (status == InjuryStatus::Questionable)
+                        .then_some(PracticeParticipation::Limited),
+                    status,
+                    last_updated: Some(Utc.with_ymd_and_hms(2024, 9, 27, 20, 0, 0).unwrap()),
+                },
+            });
+        }
+
+        let afc_east = [
+            ("BUF", 13, 4, 525, 368, Streak::Lost(1), 2),
+            ("MIA", 8, 9, 345, 364, Streak::Lost(1), 9),
+            ("NYJ", 5, 12, 338, 404, Streak::Won(1), 13),
+            ("NE", 4, 13, 289, 417, Streak::Won(1), 15),
+        ];
+        for (
+            division_rank,
+            (abbreviation, wins, losses, points_for, points_against, streak, conference_rank),
+        ) in (1..).zip(afc_east)
+        {
+            provider.add_team_standing(TeamStanding {
+                abbreviation: abbreviation.to_string(),
+                conference: "AFC".to_string(),
+                division: "AFC East".to_string(),
+                record: Record {
+                    wins,
+                    losses,
+                    ties: 0,
+                },
+                points_for,
+                points_against,
+                streak: Some(streak),
+                division_rank,
+                conference_rank,
+                seed: (conference_rank <= Standings::PLAYOFF_SPOTS).then_some(conference_rank),
+                ..Default::default()
+            });
+        }
+
+        let bills_offense = [
+            ("QB", 1, 7549, "Josh", "Allen", 17),
+            ("RB", 1, 18110, "James", "Cook", 4),
+            ("RB", 2, 21001, "Ray", "Davis", 22),
+            ("WR", 1, 17230, "Khalil", "Shakir", 10),
+            ("TE", 1, 19201, "Dalton", "Kincaid", 86),
+        ];
+        provider.add_depth_chart(DepthChart {
+            game_id: 133401,
+            team: "BUF".to_string(),
+            confirmed: true,
+            entries: bills_offense
+                .into_iter()
+                .map(
+                    |(position, depth, player_id, first_name, last_name, jersey_number)| {
+                        DepthChartEntry {
+                            side: "Offense".to_string(),
+                            position: position.to_string(),
+                            depth,
+                            player_id,
+                            first_name: first_name.to_string(),
+                            last_name: last_name.to_string(),
+                            jersey_number,
+                        }
+                    },
+                )
+                .collect(),
+        });
+
+        let quoted_at = Utc.with_ymd_and_hms(2024, 9, 28, 15, 0, 0).unwrap();
+        provider.add_game_line(GameLine {
+            game_id: 133404,
+            home_team: "BAL".to_string(),
+            away_team: "BUF".to_string(),
+            book: "FanDuel".to_string(),
+            updated_at: Some(quoted_at),
+            spread: Some(Spread {
+                home_spread: -2.5,
+                home_price: -110,
+                away_price: -110,
+            }),
+            moneyline: Some(Moneyline {
+                home: -135,
+                away: 114,
+            }),
+            total: Some(Total {
+                points: 46.5,
+                over_price: -110,
+                under_price: -110,
+            }),
+        });
+        provider.add_player_prop(PlayerProp {
+            game_id: 133404,
+            player_id: 7549,
+            first_name: "Josh".to_string(),
+            last_name: "Allen".to_string(),
+            market: "passing_yards".to_string(),
+            line: 238.5,
+            over_price: -115,
+            under_price: -105,
+            book: "FanDuel".to_string(),
+            updated_at: Some(quoted_at),
+        });
+
+        provider.add_transaction(Transaction {
+            date: NaiveDate::from_ymd_opt(2024, 10, 15).unwrap(),
+            kind: TransactionKind::Trade,
+            player: PlayerCandidate {
+                player_id: 6744,
+                first_name: "Amari".to_string(),
+                last_name: "Cooper".to_string(),
+                current_team: "BUF".to_string(),
+                primary_position: "WR".to_string(),
+            },
+            from_team: Some("CLE".to_string()),
+            to_team: Some("BUF".to_string()),
+            roster_status: "ROSTER".to_string(),
+            description: "Buffalo Bills acquired WR Amari Cooper from Cleveland Browns".to_string(),
+        });
+        provider.add_transaction(Transaction {
+            date: NaiveDate::from_ymd_opt(2024, 9, 25).unwrap(),
+            kind: TransactionKind::InjuredReserve,
+            player: PlayerCandidate {
+                player_id: 8412,
+                first_name: "Tua".to_string(),
+                last_name: "Tagovailoa".to_string(),
+                current_team: "MIA".to_string(),
+                primary_position: "QB".to_string(),
+            },
+            from_team: None,
+            to_team: Some("MIA".to_string()),
+            roster_status: "INJURED_RESERVE".to_string(),
+            description: "Miami Dolphins placed QB Tua Tagovailoa on injured reserve".to_string(),
+        });
+
         provider
     }
 
+    /// Adds a roster transaction.
+    pub fn add_transaction(&mut self, transaction: Transaction) {
+        self.transactions.push(transaction);
+    }
+
+    /// Adds a sportsbook's lines for a game.
+    pub fn add_game_line(&mut self, line: GameLine) {
+        self.game_lines.entry(line.game_id).or_default().push(line);
+    }
+
+    /// Adds a player prop for a game.
+    pub fn add_player_prop(&mut self, prop: PlayerProp) {
+        self.player_props
+            .entry(prop.game_id)
+            .or_default()
+            .push(prop);
+    }
+
+    /// Adds a team's depth chart, keyed by its game ID and team.
+    pub fn add_depth_chart(&mut self, depth_chart: DepthChart) {
+        let key = (depth_chart.game_id, depth_chart.team.to_lowercase());
+        self.depth_charts.insert(key, depth_chart);
+    }
+
+    /// Adds a team to the standings.
+    pub fn add_team_standing(&mut self, standing: TeamStanding) {
+        self.standings.push(standing);
+    }
+
+    /// Adds an entry to the injury report.
+    pub fn add_injured_player(&mut self, injured: InjuredPlayer) {
+        self.injuries.push(injured);
+    }
+
+    /// Adds the plays for a game, in the order they were run.
+    pub fn add_plays(&mut self, game_id: u64, plays: Vec<Play>) {
+        self.plays.insert(game_id, plays);
+    }
+
+    /// Adds a boxscore, keyed by its game ID.
+    pub fn add_boxscore(&mut self, boxscore: Boxscore) {
+        self.boxscores.insert(boxscore.game.game_id, boxscore);
+    }
+
+    /// Adds a game to the schedule.
+    pub fn add_game(&mut self, game: Game) {
+        self.games.push(game);
+    }
+
+    /// Adds season stats for a team, keyed by its abbreviation.
+    pub fn add_team_stats(&mut self, stats: TeamStats) {
+        self.teams.insert(stats.abbreviation.to_lowercase(), stats);
+    }
+
+    pub fn add_player_gamelogs(&mut self, name: &str, gamelogs: Vec<GameLog>) {
+        self.gamelogs.insert(name.to_string(), gamelogs);
+    }
+
+    pub fn add_player_profile(&mut self, name: &str, profile: PlayerProfile) {
+        self.profiles.insert(name.to_string(), profile);
+    }
+
+    /// Adds stats for a player name. Adding several players under the same
+    /// name makes lookups for that name ambiguous.
     pub fn add_player_stats(&mut self, name: &str, stats: PlayerStats) {
-        self.responses.insert(name.to_string(), stats);
+        self.responses
+            .entry(name.to_string())
+            .or_default()
+            .push(stats);
     }
 
     pub fn add_player_error(&mut self, name: &str, error: StatbookError) {
@@ -77,28 +621,208 @@ impl MockStatsProvider {
 #[async_trait]
 impl StatsProvider for MockStatsProvider {
     async fn fetch_player_stats(&self, name: &str, season: &str) -> Result<PlayerStats> {
+        self.fetch_player_stats_with_hint(name, season, &PlayerHint::default())
+            .await
+    }
+
+    async fn fetch_player_stats_with_hint(
+        &self,
+        name: &str,
+        season: &str,
+        hint: &PlayerHint,
+    ) -> Result<PlayerStats> {
         if let Some(_error) = self.errors.get(name) {
             return Err(StatbookError::PlayerNotFound {
                 name: name.to_string(),
             });
         }
 
-        match self.responses.get(name) {
+        let candidates = self
+            .responses
+            .get(name)
+            .map(|players| {
+                players
+                    .iter()
+                    .map(|stats| {
+                        let mut stats_with_season = stats.clone();
+                        stats_with_season.season = season.to_string();
+                        stats_with_season
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        hint.resolve(name, candidates)
+    }
+
+    async fn fetch_player_stats_by_id(&self, player_id: u64, season: &str) -> Result<PlayerStats> {
+        match self
+            .responses
+            .values()
+            .flatten()
+            .find(|stats| stats.player_id == player_id)
+        {
             Some(stats) => {
                 let mut stats_with_season = stats.clone();
                 stats_with_season.season = season.to_string();
                 Ok(stats_with_season)
             }
             None => Err(StatbookError::PlayerNotFound {
+                name: player_id.to_string(),
+            }),
+        }
+    }
+
+    async fn fetch_player_profile(&self, name: &str) -> Result<PlayerProfile> {
+        if self.errors.contains_key(name) {
+            return Err(StatbookError::PlayerNotFound {
                 name: name.to_string(),
+            });
+        }
+
+        self.profiles
+            .get(name)
+            .cloned()
+            .ok_or_else(|| StatbookError::PlayerNotFound {
+                name: name.to_string(),
+            })
+    }
+
+    async fn fetch_player_gamelogs(
+        &self,
+        name: &str,
+        _season: &str,
+        week_range: Option<(u32, u32)>,
+    ) -> Result<Vec<GameLog>> {
+        if self.errors.contains_key(name) {
+            return Err(StatbookError::PlayerNotFound {
+                name: name.to_string(),
+            });
+        }
+
+        let gamelogs = self.gamelogs.get(name).cloned().unwrap_or_default();
+        Ok(match week_range {
+            Some((first, last)) => gamelogs
+                .into_iter()
+                .filter(|gamelog| (first..=last).contains(&gamelog.week))
+                .collect(),
+            None => gamelogs,
+        })
+    }
+
+    async fn fetch_team_stats(&self, team: &str, season: &str) -> Result<TeamStats> {
+        match self.teams.get(&team.to_lowercase()) {
+            Some(stats) => {
+                let mut stats_with_season = stats.clone();
+                stats_with_season.season = season.to_string();
+                Ok(stats_with_season)
+            }
+            None => Err(StatbookError::TeamNotFound {
+                team: team.to_string(),
             }),
         }
     }
+
+    async fn fetch_standings(&self, season: &str) -> Result<Standings> {
+        Ok(Standings {
+            teams: self.standings.clone(),
+            season: season.to_string(),
+        })
+    }
+
+    async fn fetch_schedule(&self, _season: &str) -> Result<Vec<Game>> {
+        let mut games = self.games.clone();
+        // Games without a kickoff time go last rather than sorting as the earliest
+        games.sort_by_key(|game| (game.start_time.is_none(), game.start_time));
+        Ok(games)
+    }
+
+    async fn fetch_game_boxscore(&self, game_id: u64, _season: &str) -> Result<Boxscore> {
+        self.boxscores
+            .get(&game_id)
+            .cloned()
+            .ok_or(StatbookError::GameNotFound { game_id })
+    }
+
+    async fn fetch_play_by_play(&self, game_id: u64, _season: &str) -> Result<Vec<Play>> {
+        self.plays
+            .get(&game_id)
+            .cloned()
+            .ok_or(StatbookError::GameNotFound { game_id })
+    }
+
+    async fn fetch_depth_chart(
+        &self,
+        team: &str,
+        game_id: u64,
+        _season: &str,
+    ) -> Result<DepthChart> {
+        if !self.games.iter().any(|game| game.game_id == game_id) {
+            return Err(StatbookError::GameNotFound { game_id });
+        }
+
+        self.depth_charts
+            .get(&(game_id, team.to_lowercase()))
+            .cloned()
+            .ok_or_else(|| StatbookError::TeamNotFound {
+                team: team.to_string(),
+            })
+    }
+
+    async fn fetch_game_lines(&self, game_id: u64, _season: &str) -> Result<Vec<GameLine>> {
+        Ok(self.game_lines.get(&game_id).cloned().unwrap_or_default())
+    }
+
+    async fn fetch_player_props(&self, game_id: u64, _season: &str) -> Result<Vec<PlayerProp>> {
+        Ok(self.player_props.get(&game_id).cloned().unwrap_or_default())
+    }
+
+    async fn fetch_transactions(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        team: Option<&str>,
+    ) -> Result<Vec<Transaction>> {
+        let mut transactions: Vec<Transaction> = self
+            .transactions
+            .iter()
+            .filter(|transaction| (from..=to).contains(&transaction.date))
+            .filter(|transaction| match team {
+                Some(team) => transaction.involves(team),
+                None => true,
+            })
+            .cloned()
+            .collect();
+        transactions.sort_by_key(|transaction| transaction.date);
+        Ok(transactions)
+    }
+
+    async fn fetch_injuries(&self, team: Option<&str>) -> Result<Vec<InjuredPlayer>> {
+        Ok(self
+            .injuries
+            .iter()
+            .filter(|injured| match team {
+                Some(team) => injured.player.current_team.eq_ignore_ascii_case(team),
+                None => true,
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_players(&self) -> Result<Vec<PlayerProfile>> {
+        let mut players: Vec<PlayerProfile> = self.profiles.values().cloned().collect();
+        players.sort_by_key(|profile| profile.player_id);
+        Ok(players)
+    }
 }
 
 #[derive(Debug)]
 pub struct MockNewsProvider {
     responses: HashMap<String, Vec<Article>>,
+    team_news: HashMap<String, Vec<Article>>,
+    headlines: Vec<Article>,
+    undated: HashMap<String, Vec<usize>>,
+    result_limit: Option<u32>,
     errors: HashMap<String, StatbookError>,
 }
 
@@ -106,6 +830,10 @@ impl MockNewsProvider {
     pub fn new() -> Self {
         Self {
             responses: HashMap::new(),
+            team_news: HashMap::new(),
+            headlines: Vec::new(),
+            undated: HashMap::new(),
+            result_limit: None,
             errors: HashMap::new(),
         }
     }
@@ -120,13 +848,23 @@ impl MockNewsProvider {
                 Article {
                     title: "Josh Allen leads Bills to victory".to_string(),
                     description: "Quarterback throws for 300 yards".to_string(),
-                    published_at: "2024-01-15T10:00:00Z".to_string(),
+                    url: "https://example.com/news/allen-leads-bills".to_string(),
+                    source_id: Some("espn".to_string()),
+                    source_name: "ESPN".to_string(),
+                    author: Some("Staff Writer".to_string()),
+                    image_url: Some("https://example.com/images/allen.jpg".to_string()),
+                    published_at: Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(),
                     content: "Full article content here...".to_string(),
                 },
                 Article {
                     title: "Allen named AFC Player of the Week".to_string(),
                     description: "Recognition for outstanding performance".to_string(),
-                    published_at: "2024-01-14T15:30:00Z".to_string(),
+                    url: "https://example.com/news/allen-player-of-the-week".to_string(),
+                    source_id: None,
+                    source_name: "Buffalo News".to_string(),
+                    author: None,
+                    image_url: None,
+                    published_at: Utc.with_ymd_and_hms(2024, 1, 14, 15, 30, 0).unwrap(),
                     content: "More article content...".to_string(),
                 },
             ],
@@ -137,11 +875,56 @@ impl MockNewsProvider {
             vec![Article {
                 title: "Brady announces retirement".to_string(),
                 description: "Legendary quarterback calls it a career".to_string(),
-                published_at: "2024-01-10T12:00:00Z".to_string(),
+                url: "https://example.com/news/brady-retires".to_string(),
+                source_id: Some("nfl-news".to_string()),
+                source_name: "NFL News".to_string(),
+                author: None,
+                image_url: None,
+                published_at: Utc.with_ymd_and_hms(2024, 1, 10, 12, 0, 0).unwrap(),
                 content: "Retirement announcement content...".to_string(),
             }],
         );
 
+        provider.add_team_news(
+            "Buffalo Bills",
+            vec![Article {
+                title: "Bills acquire Amari Cooper from Browns".to_string(),
+                description: "Buffalo adds a veteran receiver before the deadline".to_string(),
+                url: "https://example.com/news/bills-acquire-cooper".to_string(),
+                source_id: Some("espn".to_string()),
+                source_name: "ESPN".to_string(),
+                author: None,
+                image_url: None,
+                published_at: Utc.with_ymd_and_hms(2024, 10, 15, 18, 0, 0).unwrap(),
+                content: "Trade content...".to_string(),
+            }],
+        );
+
+        provider.add_headlines(vec![
+            Article {
+                title: "NFL Week 7 takeaways".to_string(),
+                description: "What we learned from Sunday's games".to_string(),
+                url: "https://example.com/news/week-7-takeaways".to_string(),
+                source_id: Some("nfl-news".to_string()),
+                source_name: "NFL News".to_string(),
+                author: None,
+                image_url: None,
+                published_at: Utc.with_ymd_and_hms(2024, 10, 21, 9, 0, 0).unwrap(),
+                content: "Takeaways content...".to_string(),
+            },
+            Article {
+                title: "Trade deadline tracker".to_string(),
+                description: "Every move ahead of the deadline".to_string(),
+                url: "https://example.com/news/trade-deadline-tracker".to_string(),
+                source_id: Some("espn".to_string()),
+                source_name: "ESPN".to_string(),
+                author: None,
+                image_url: None,
+                published_at: Utc.with_ymd_and_hms(2024, 10, 20, 12, 0, 0).unwrap(),
+                content: "Tracker content...".to_string(),
+            },
+        ]);
+
         provider
     }
 
@@ -149,6 +932,30 @@ impl MockNewsProvider {
         self.responses.insert(player_name.to_string(), articles);
     }
 
+    /// Adds news articles for a team, keyed case-insensitively by team name.
+    pub fn add_team_news(&mut self, team: &str, articles: Vec<Article>) {
+        self.team_news.insert(team.to_lowercase(), articles);
+    }
+
+    /// Adds articles to the sports headlines.
+    pub fn add_headlines(&mut self, articles: Vec<Article>) {
+        self.headlines.extend(articles);
+    }
+
+    /// Marks a player's articles at the given positions as undated.
+    ///
+    /// They still count toward the total but are left off their page, as the
+    /// real provider drops articles it cannot date.
+    pub fn mark_undated(&mut self, player_name: &str, positions: Vec<usize>) {
+        self.undated.insert(player_name.to_string(), positions);
+    }
+
+    /// Refuses player news pages that reach past `limit` results, as NewsAPI
+    /// does on its developer tier.
+    pub fn set_result_limit(&mut self, limit: u32) {
+        self.result_limit = Some(limit);
+    }
+
     pub fn add_news_error(&mut self, player_name: &str, error: StatbookError) {
         self.errors.insert(player_name.to_string(), error);
     }
@@ -164,11 +971,55 @@ impl NewsProvider for MockNewsProvider {
             });
         }
 
+        if let Some(limit) = self.result_limit {
+            if query.page * query.page_size > limit {
+                return Err(StatbookError::NewsResultLimit {
+                    message: format!("Results are limited to a maximum of {limit}"),
+                });
+            }
+        }
+
         match self.responses.get(&query.player_name) {
-            Some(articles) => Ok(PlayerNews::new(articles.clone(), query.clone())),
+            Some(articles) => {
+                let skip = query.page.saturating_sub(1) as usize * query.page_size as usize;
+                let undated = self.undated.get(&query.player_name);
+                let page = articles
+                    .iter()
+                    .enumerate()
+                    .skip(skip)
+                    .take(query.page_size as usize)
+                    .filter(|(position, _)| !undated.is_some_and(|u| u.contains(position)))
+                    .map(|(_, article)| article.clone())
+                    .collect();
+                Ok(PlayerNews::new(page, query.clone()).with_total_count(articles.len() as u32))
+            }
             None => Ok(PlayerNews::new(vec![], query.clone())), // Return empty PlayerNews for unknown players
         }
     }
+
+    async fn fetch_team_news(&self, query: &NewsQuery) -> Result<Vec<Article>> {
+        let articles = self
+            .team_news
+            .get(&query.player_name.to_lowercase())
+            .map(|articles| {
+                articles
+                    .iter()
+                    .take(query.page_size as usize)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(articles)
+    }
+
+    async fn fetch_headlines(&self, page_size: u32) -> Result<Vec<Article>> {
+        Ok(self
+            .headlines
+            .iter()
+            .take(page_size as usize)
+            .cloned()
+            .collect())
+    }
 }
 
 impl Default for MockStatsProvider {
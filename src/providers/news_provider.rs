@@ -1,10 +1,16 @@
 use crate::{
     config::StatbookConfig,
     error::{Result, StatbookError},
-    models::{parsers::news_parser::NewsResponse, Article, NewsQuery, PlayerNews},
+    models::{
+        parsers::news_parser::{NewsErrorResponse, NewsResponse},
+        Article, NewsQuery, PlayerNews,
+    },
 };
 use async_trait::async_trait;
 
+/// Error code NewsAPI returns when a request pages past the plan's result limit.
+const RESULT_LIMIT_CODE: &str = "maximumResultsReached";
+
 /// Trait for providing news articles from various data sources.
 ///
 /// This trait abstracts the news fetching logic, allowing for different
@@ -28,21 +34,67 @@ pub trait NewsProvider: Send + Sync {
     /// This method will return an error if:
     /// - Network request fails
     /// - API returns an error response (e.g., invalid API key, rate limit)
+    /// - The page lies past the provider's result limit
+    ///   (`StatbookError::NewsResultLimit`)
     /// - Response parsing fails
     async fn fetch_player_news(&self, query: &NewsQuery) -> Result<PlayerNews>;
+
+    /// Fetches recent news articles about a team.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Search parameters whose `player_name` holds the team name
+    ///   (e.g., "Buffalo Bills"), usually built with `StatbookClient::news_query()`
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - Network request fails
+    /// - API returns an error response (e.g., invalid API key, rate limit)
+    /// - Response parsing fails
+    /// - The provider does not support team news (default implementation)
+    async fn fetch_team_news(&self, query: &NewsQuery) -> Result<Vec<Article>> {
+        let _ = query;
+        Err(StatbookError::Unsupported {
+            operation: "fetch_team_news".to_string(),
+        })
+    }
+
+    /// Fetches the current sports headlines.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - Maximum number of articles to return
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - Network request fails
+    /// - API returns an error response (e.g., invalid API key, rate limit)
+    /// - Response parsing fails
+    /// - The provider does not support headlines (default implementation)
+    async fn fetch_headlines(&self, page_size: u32) -> Result<Vec<Article>> {
+        let _ = page_size;
+        Err(StatbookError::Unsupported {
+            operation: "fetch_headlines".to_string(),
+        })
+    }
 }
 
 /// NewsAPI implementation of the `NewsProvider` trait.
 ///
 /// This provider fetches news articles from NewsAPI.org, which aggregates
 /// news from thousands of sources worldwide. It's particularly useful for
-/// getting recent coverage of sports players and teams.
+/// getting recent coverage of sports players and teams. Team news searches
+/// the `everything` endpoint with the query it is given; headlines come
+/// from `top-headlines` in the sports category.
 ///
 /// # Free Tier Compatibility
 ///
 /// This implementation is compatible with NewsAPI's free tier by default.
 /// Date filtering (using the `from` parameter) is only applied when explicitly
-/// set via `NewsQuery::with_date_range()`, as it requires a paid subscription.
+/// set via `NewsQuery::with_date_range()` or derived from `NewsConfig::days_back`
+/// with `NewsConfig::paid_tier` enabled, as it requires a paid subscription.
 ///
 /// # Rate Limits
 ///
@@ -65,58 +117,90 @@ impl NewsApiProvider {
             http_client: reqwest::Client::new(),
         }
     }
-}
 
-#[async_trait]
-impl NewsProvider for NewsApiProvider {
-    async fn fetch_player_news(&self, query: &NewsQuery) -> Result<PlayerNews> {
-        let url = format!("{}/everything", self.config.news_base_url);
-
-        // Build query parameters, only including 'from' if it's not empty
-        // This ensures compatibility with NewsAPI free tier which doesn't support date filtering
-        let page_size_str = query.page_size.to_string();
-        let mut query_params = vec![
-            ("q", query.player_name.as_str()),
-            ("pageSize", page_size_str.as_str()),
-            ("sortBy", query.sort_by.as_str()),
-            ("apiKey", self.config.news_api_key.as_str()),
-        ];
-
-        // Only add 'from' parameter if it's not empty (paid tier feature)
-        if !query.from_date.is_empty() {
-            query_params.push(("from", query.from_date.as_str()));
-        }
+    /// Requests a NewsAPI endpoint and parses the article list it returns.
+    async fn get_articles(
+        &self,
+        endpoint: &str,
+        query: &[(&str, String)],
+        context: &str,
+    ) -> Result<NewsResponse> {
+        let url = format!("{}/{endpoint}", self.config.news_base_url);
 
         let response = self
             .http_client
             .get(&url)
             .header("User-Agent", "FantasyFootballApp/1.0")
-            .query(&query_params)
+            .query(query)
             .send()
             .await?;
 
         if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            let error: NewsErrorResponse = serde_json::from_str(&body).unwrap_or_default();
+
+            if error.code.as_deref() == Some(RESULT_LIMIT_CODE) {
+                return Err(StatbookError::NewsResultLimit {
+                    message: error.message.unwrap_or_default(),
+                });
+            }
+
             return Err(StatbookError::NewsApi {
-                status: response.status().as_u16(),
-                message: format!("Failed to fetch news for '{}'", query.player_name),
+                status,
+                message: format!("Failed to fetch {context}"),
             });
         }
 
         let json = response.text().await?;
-        let news_data: NewsResponse = serde_json::from_str(&json)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+#[async_trait]
+impl NewsProvider for NewsApiProvider {
+    async fn fetch_player_news(&self, query: &NewsQuery) -> Result<PlayerNews> {
+        let mut query_params = query.query_params();
+        query_params.push(("apiKey", self.config.news_api_key.clone()));
+
+        let news_data = self
+            .get_articles(
+                "everything",
+                &query_params,
+                &format!("news for '{}'", query.player_name),
+            )
+            .await?;
 
         let articles = news_data
             .articles
             .iter()
-            .map(|article| Article {
-                title: article.title.clone().unwrap_or_default(),
-                content: article.content.clone().unwrap_or_default(),
-                description: article.description.clone().unwrap_or_default(),
-                published_at: article.published_at.clone().unwrap_or_default(),
-            })
+            .filter_map(Article::from_parsed)
             .collect();
 
         Ok(PlayerNews::new(articles, query.clone())
             .with_total_count(news_data.total_results as u32))
     }
+
+    async fn fetch_team_news(&self, query: &NewsQuery) -> Result<Vec<Article>> {
+        let news = self.fetch_player_news(query).await?;
+        Ok(news.articles)
+    }
+
+    async fn fetch_headlines(&self, page_size: u32) -> Result<Vec<Article>> {
+        let query_params = [
+            ("category", "sports".to_string()),
+            ("pageSize", page_size.to_string()),
+            ("apiKey", self.config.news_api_key.clone()),
+        ];
+
+        let news_data = self
+            .get_articles("top-headlines", &query_params, "headlines")
+            .await?;
+
+        Ok(news_data
+            .articles
+            .iter()
+            .filter_map(Article::from_parsed)
+            .collect())
+    }
 }
@@ -1,10 +1,25 @@
 use crate::{
     config::StatbookConfig,
     error::{Result, StatbookError},
-    models::{parsers::player_parser::PlayerResponse, PlayerStats},
+    models::{
+        parsers::{
+            game_parser::{BoxscoreResponse, GamesResponse, LineupResponse, PlayByPlayResponse},
+            odds_parser::GameLinesResponse,
+            player_parser::{
+                GamelogResponse, InjuriesResponse, PlayerResponse, PlayersResponse,
+                TransactionsResponse,
+            },
+            team_parser::{StandingsResponse, TeamStatsResponse},
+        },
+        Boxscore, DepthChart, Game, GameLine, GameLog, InjuredPlayer, Injury, Play,
+        PlayerCandidate, PlayerHint, PlayerProfile, PlayerProp, PlayerStats, Standings,
+        TeamStanding, TeamStats, Transaction,
+    },
 };
 use async_trait::async_trait;
 use base64::{engine::general_purpose, Engine as _};
+use chrono::NaiveDate;
+use serde::de::DeserializeOwned;
 
 const STATBOOK_PASSWORD: &str = "MYSPORTSFEEDS";
 
@@ -34,6 +49,381 @@ pub trait StatsProvider: Send + Sync {
     /// - API returns an error response
     /// - Response parsing fails
     async fn fetch_player_stats(&self, name: &str, season: &str) -> Result<PlayerStats>;
+
+    /// Fetches player statistics by name, using a hint to pick between
+    /// players who share that name.
+    ///
+    /// The default implementation looks the name up with `fetch_player_stats`
+    /// and checks the single result against the hint; providers that can list
+    /// every match should override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The player name to search for (e.g., "josh-allen")
+    /// * `season` - Season identifier (e.g., "2023-2024-regular")
+    /// * `hint` - Team and/or position used to disambiguate matches
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - No player matches both the name and the hint
+    /// - More than one player matches (`StatbookError::AmbiguousPlayer`)
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    async fn fetch_player_stats_with_hint(
+        &self,
+        name: &str,
+        season: &str,
+        hint: &PlayerHint,
+    ) -> Result<PlayerStats> {
+        let stats = self.fetch_player_stats(name, season).await?;
+        hint.resolve(name, vec![stats])
+    }
+
+    /// Fetches player statistics by the provider's numeric player ID.
+    ///
+    /// Unlike name lookups, IDs are stable across spelling variations and
+    /// always identify a single player.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The provider's player ID (see `PlayerStats::player_id`)
+    /// * `season` - Season identifier (e.g., "2023-2024-regular")
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - No player has the given ID
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support ID lookups (default implementation)
+    async fn fetch_player_stats_by_id(&self, player_id: u64, season: &str) -> Result<PlayerStats> {
+        let _ = (player_id, season);
+        Err(StatbookError::Unsupported {
+            operation: "fetch_player_stats_by_id".to_string(),
+        })
+    }
+
+    /// Fetches a player's biographical profile by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The player name to search for (e.g., "josh-allen")
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The player is not found
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support profiles (default implementation)
+    async fn fetch_player_profile(&self, name: &str) -> Result<PlayerProfile> {
+        let _ = name;
+        Err(StatbookError::Unsupported {
+            operation: "fetch_player_profile".to_string(),
+        })
+    }
+
+    /// Fetches a player's per-game stat lines for a season.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The player name to search for (e.g., "josh-allen")
+    /// * `season` - Season identifier (e.g., "2023-2024-regular")
+    /// * `week_range` - Inclusive range of weeks to fetch, or `None` for the whole season
+    ///
+    /// # Returns
+    ///
+    /// Returns the player's game logs ordered by kickoff time. The list is
+    /// empty if the player has not appeared in a game in those weeks.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - More than one player matches (`StatbookError::AmbiguousPlayer`)
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support game logs (default implementation)
+    async fn fetch_player_gamelogs(
+        &self,
+        name: &str,
+        season: &str,
+        week_range: Option<(u32, u32)>,
+    ) -> Result<Vec<GameLog>> {
+        let _ = (name, season, week_range);
+        Err(StatbookError::Unsupported {
+            operation: "fetch_player_gamelogs".to_string(),
+        })
+    }
+
+    /// Fetches season statistics for a team.
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - Team abbreviation (e.g., "buf")
+    /// * `season` - Season identifier (e.g., "2023-2024-regular")
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The team is not found
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support team stats (default implementation)
+    async fn fetch_team_stats(&self, team: &str, season: &str) -> Result<TeamStats> {
+        let _ = (team, season);
+        Err(StatbookError::Unsupported {
+            operation: "fetch_team_stats".to_string(),
+        })
+    }
+
+    /// Fetches league standings for a season.
+    ///
+    /// # Arguments
+    ///
+    /// * `season` - Season identifier (e.g., "2023-2024-regular")
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support standings (default implementation)
+    async fn fetch_standings(&self, season: &str) -> Result<Standings> {
+        let _ = season;
+        Err(StatbookError::Unsupported {
+            operation: "fetch_standings".to_string(),
+        })
+    }
+
+    /// Fetches every game on a season's schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `season` - Season identifier (e.g., "2023-2024-regular")
+    ///
+    /// # Returns
+    ///
+    /// Returns the season's games ordered by kickoff time, including games
+    /// that have not been played yet.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support schedules (default implementation)
+    async fn fetch_schedule(&self, season: &str) -> Result<Vec<Game>> {
+        let _ = season;
+        Err(StatbookError::Unsupported {
+            operation: "fetch_schedule".to_string(),
+        })
+    }
+
+    /// Fetches the boxscore for a single game.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_id` - The provider's game ID (see `Game::game_id`)
+    /// * `season` - Season identifier the game belongs to (e.g., "latest")
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The game is not found
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support boxscores (default implementation)
+    async fn fetch_game_boxscore(&self, game_id: u64, season: &str) -> Result<Boxscore> {
+        let _ = (game_id, season);
+        Err(StatbookError::Unsupported {
+            operation: "fetch_game_boxscore".to_string(),
+        })
+    }
+
+    /// Fetches every play from a single game.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_id` - The provider's game ID (see `Game::game_id`)
+    /// * `season` - Season identifier the game belongs to (e.g., "latest")
+    ///
+    /// # Returns
+    ///
+    /// Returns the game's plays in the order they were run. Games that have
+    /// not started return an empty list.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The game is not found
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support play-by-play (default implementation)
+    async fn fetch_play_by_play(&self, game_id: u64, season: &str) -> Result<Vec<Play>> {
+        let _ = (game_id, season);
+        Err(StatbookError::Unsupported {
+            operation: "fetch_play_by_play".to_string(),
+        })
+    }
+
+    /// Fetches a team's depth chart for a single game.
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - Team abbreviation (e.g., "buf")
+    /// * `game_id` - The provider's game ID (see `Game::game_id`)
+    /// * `season` - Season identifier the game belongs to (e.g., "latest")
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The game is not found
+    /// - The team is not playing in the game
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support depth charts (default implementation)
+    async fn fetch_depth_chart(
+        &self,
+        team: &str,
+        game_id: u64,
+        season: &str,
+    ) -> Result<DepthChart> {
+        let _ = (team, game_id, season);
+        Err(StatbookError::Unsupported {
+            operation: "fetch_depth_chart".to_string(),
+        })
+    }
+
+    /// Fetches every sportsbook's current lines for a game.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_id` - The provider's game ID (see `Game::game_id`)
+    /// * `season` - Season identifier the game belongs to (e.g., "latest")
+    ///
+    /// # Returns
+    ///
+    /// Returns one `GameLine` per sportsbook. The list is empty if no book
+    /// has posted lines for the game yet.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support odds (default implementation)
+    async fn fetch_game_lines(&self, game_id: u64, season: &str) -> Result<Vec<GameLine>> {
+        let _ = (game_id, season);
+        Err(StatbookError::Unsupported {
+            operation: "fetch_game_lines".to_string(),
+        })
+    }
+
+    /// Fetches player prop lines for a game.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_id` - The provider's game ID (see `Game::game_id`)
+    /// * `season` - Season identifier the game belongs to (e.g., "latest")
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support player props (default implementation)
+    async fn fetch_player_props(&self, game_id: u64, season: &str) -> Result<Vec<PlayerProp>> {
+        let _ = (game_id, season);
+        Err(StatbookError::Unsupported {
+            operation: "fetch_player_props".to_string(),
+        })
+    }
+
+    /// Fetches the current league injury report.
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - Team abbreviation to restrict the report to, or `None` for every team
+    ///
+    /// # Returns
+    ///
+    /// Returns every currently injured player. Healthy players are omitted.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support injury reports (default implementation)
+    async fn fetch_injuries(&self, team: Option<&str>) -> Result<Vec<InjuredPlayer>> {
+        let _ = team;
+        Err(StatbookError::Unsupported {
+            operation: "fetch_injuries".to_string(),
+        })
+    }
+
+    /// Fetches roster transactions between two dates.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - First day to include
+    /// * `to` - Last day to include
+    /// * `team` - Team abbreviation to restrict the moves to, or `None` for every team
+    ///
+    /// # Returns
+    ///
+    /// Returns the transactions in date order.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support transactions (default implementation)
+    async fn fetch_transactions(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        team: Option<&str>,
+    ) -> Result<Vec<Transaction>> {
+        let _ = (from, to, team);
+        Err(StatbookError::Unsupported {
+            operation: "fetch_transactions".to_string(),
+        })
+    }
+
+    /// Fetches profiles for every player the provider knows about.
+    ///
+    /// Used to build the player search index, so implementations should
+    /// include at least all currently rostered players.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - Network request fails
+    /// - API returns an error response
+    /// - Response parsing fails
+    /// - The provider does not support listing players (default implementation)
+    async fn fetch_players(&self) -> Result<Vec<PlayerProfile>> {
+        Err(StatbookError::Unsupported {
+            operation: "fetch_players".to_string(),
+        })
+    }
 }
 
 /// MySports API implementation of the `StatsProvider` trait.
@@ -42,6 +432,9 @@ pub trait StatsProvider: Send + Sync {
 /// which provides comprehensive NFL player data including current
 /// season statistics, team information, and injury status.
 ///
+/// MySports does not publish player props, so `fetch_player_props()`
+/// returns `StatbookError::Unsupported`.
+///
 /// # Authentication
 ///
 /// MySports API uses HTTP Basic Authentication with the API key as
@@ -63,62 +456,410 @@ impl MySportsStatsProvider {
             http_client: reqwest::Client::new(),
         }
     }
-}
 
-#[async_trait]
-impl StatsProvider for MySportsStatsProvider {
-    async fn fetch_player_stats(&self, name: &str, season: &str) -> Result<PlayerStats> {
+    /// Sends an authenticated GET request to a MySports NFL feed and parses
+    /// the JSON response.
+    ///
+    /// # Arguments
+    ///
+    /// * `feed` - Feed path below `/pull/nfl/` (e.g., "players.json")
+    /// * `query` - Query string parameters
+    /// * `context` - Description of the request used in error messages
+    async fn get_feed<T: DeserializeOwned>(
+        &self,
+        feed: &str,
+        query: &[(&str, &str)],
+        context: &str,
+    ) -> Result<T> {
         let credentials = format!("{}:{}", self.config.stats_api_key, STATBOOK_PASSWORD);
         let encoded_credentials = general_purpose::STANDARD.encode(&credentials);
         let auth_header = format!("Basic {encoded_credentials}");
-        let url = format!(
-            "{}/pull/nfl/{season}/player_stats_totals.json",
-            self.config.stats_base_url,
-        );
+        let url = format!("{}/pull/nfl/{feed}", self.config.stats_base_url);
 
         let response = self
             .http_client
             .get(&url)
             .header("Authorization", auth_header)
-            .query(&[("player", name)])
+            .query(query)
             .send()
             .await?;
 
         if !response.status().is_success() {
             return Err(StatbookError::StatsApi {
                 status: response.status().as_u16(),
-                message: format!("Failed to fetch player stats for '{name}'"),
+                message: format!("Failed to fetch {context}"),
             });
         }
 
         let json = response.text().await?;
-        let player_data: PlayerResponse = serde_json::from_str(&json)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
 
-        let player = match player_data.players.first() {
-            Some(player) => player,
-            None => {
-                return Err(StatbookError::PlayerNotFound {
-                    name: name.to_string(),
-                })
+/// Maps a 404 from a per-game feed to `StatbookError::GameNotFound`.
+fn game_not_found(game_id: u64) -> impl FnOnce(StatbookError) -> StatbookError {
+    move |error| match error {
+        StatbookError::StatsApi { status: 404, .. } => StatbookError::GameNotFound { game_id },
+        error => error,
+    }
+}
+
+#[async_trait]
+impl StatsProvider for MySportsStatsProvider {
+    async fn fetch_player_stats(&self, name: &str, season: &str) -> Result<PlayerStats> {
+        self.fetch_player_stats_with_hint(name, season, &PlayerHint::default())
+            .await
+    }
+
+    async fn fetch_player_stats_with_hint(
+        &self,
+        name: &str,
+        season: &str,
+        hint: &PlayerHint,
+    ) -> Result<PlayerStats> {
+        let player_data: PlayerResponse = self
+            .get_feed(
+                &format!("{season}/player_stats_totals.json"),
+                &[("player", name)],
+                &format!("player stats for '{name}'"),
+            )
+            .await?;
+
+        let candidates = player_data
+            .players
+            .iter()
+            .map(|player| PlayerStats::from_parsed(player, season))
+            .collect();
+
+        hint.resolve(name, candidates)
+    }
+
+    async fn fetch_player_stats_by_id(&self, player_id: u64, season: &str) -> Result<PlayerStats> {
+        let id = player_id.to_string();
+        let player_data: PlayerResponse = self
+            .get_feed(
+                &format!("{season}/player_stats_totals.json"),
+                &[("player", id.as_str())],
+                &format!("player stats for player ID {id}"),
+            )
+            .await?;
+
+        match player_data.players.first() {
+            Some(player) => Ok(PlayerStats::from_parsed(player, season)),
+            None => Err(StatbookError::PlayerNotFound { name: id }),
+        }
+    }
+
+    async fn fetch_player_profile(&self, name: &str) -> Result<PlayerProfile> {
+        let player_data: PlayersResponse = self
+            .get_feed(
+                "players.json",
+                &[("player", name)],
+                &format!("player profile for '{name}'"),
+            )
+            .await?;
+
+        player_data
+            .players
+            .first()
+            .map(|entry| PlayerProfile::from_parsed(&entry.player_info))
+            .ok_or_else(|| StatbookError::PlayerNotFound {
+                name: name.to_string(),
+            })
+    }
+
+    async fn fetch_players(&self) -> Result<Vec<PlayerProfile>> {
+        let player_data: PlayersResponse =
+            self.get_feed("players.json", &[], "player list").await?;
+
+        Ok(player_data
+            .players
+            .iter()
+            .map(|entry| PlayerProfile::from_parsed(&entry.player_info))
+            .collect())
+    }
+
+    async fn fetch_player_gamelogs(
+        &self,
+        name: &str,
+        season: &str,
+        week_range: Option<(u32, u32)>,
+    ) -> Result<Vec<GameLog>> {
+        let weeks = week_range.map(|(first, last)| {
+            (first..=last)
+                .map(|week| week.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        });
+        let mut query = vec![("player", name)];
+        if let Some(weeks) = weeks.as_deref() {
+            query.push(("week", weeks));
+        }
+
+        let gamelog_data: GamelogResponse = self
+            .get_feed(
+                &format!("{season}/player_gamelogs.json"),
+                &query,
+                &format!("game logs for '{name}'"),
+            )
+            .await?;
+
+        let mut candidates: Vec<PlayerCandidate> = Vec::new();
+        for gamelog in &gamelog_data.gamelogs {
+            let candidate = PlayerCandidate::from_parsed(&gamelog.player_info);
+            if !candidates
+                .iter()
+                .any(|c| c.player_id == candidate.player_id)
+            {
+                candidates.push(candidate);
             }
-        };
-
-        let player_info = &player.player_info;
-
-        Ok(PlayerStats {
-            first_name: player_info.first_name.clone().unwrap_or_default(),
-            last_name: player_info.last_name.clone().unwrap_or_default(),
-            primary_position: player_info.primary_position.clone().unwrap_or_default(),
-            jersey_number: player_info.jersey_number.unwrap_or(0),
-            current_team: player_info
-                .current_team
-                .as_ref()
-                .and_then(|team| team.abbreviation.clone())
-                .unwrap_or_default(),
-            injury: player_info.injury.clone().unwrap_or_default(),
-            rookie: player_info.rookie.unwrap_or(false),
-            games_played: player.statistics.games_played.unwrap_or(0),
+        }
+        if candidates.len() > 1 {
+            return Err(StatbookError::AmbiguousPlayer {
+                name: name.to_string(),
+                candidates,
+            });
+        }
+
+        let mut gamelogs: Vec<GameLog> = gamelog_data
+            .gamelogs
+            .iter()
+            .map(GameLog::from_parsed)
+            .collect();
+        // Games without a kickoff time go last rather than sorting as the earliest
+        gamelogs.sort_by_key(|gamelog| (gamelog.start_time.is_none(), gamelog.start_time));
+
+        Ok(gamelogs)
+    }
+
+    async fn fetch_team_stats(&self, team: &str, season: &str) -> Result<TeamStats> {
+        let team_data: TeamStatsResponse = self
+            .get_feed(
+                &format!("{season}/team_stats_totals.json"),
+                &[("team", team)],
+                &format!("team stats for '{team}'"),
+            )
+            .await?;
+
+        team_data
+            .teams
+            .iter()
+            .find(|entry| {
+                entry
+                    .team_info
+                    .abbreviation
+                    .as_deref()
+                    .is_some_and(|abbreviation| abbreviation.eq_ignore_ascii_case(team))
+            })
+            .map(|entry| TeamStats::from_parsed(entry, season))
+            .ok_or_else(|| StatbookError::TeamNotFound {
+                team: team.to_string(),
+            })
+    }
+
+    async fn fetch_schedule(&self, season: &str) -> Result<Vec<Game>> {
+        let game_data: GamesResponse = self
+            .get_feed(
+                &format!("{season}/games.json"),
+                &[],
+                &format!("schedule for '{season}'"),
+            )
+            .await?;
+
+        let mut games: Vec<Game> = game_data
+            .games
+            .iter()
+            .map(|entry| Game::from_parsed(&entry.schedule, entry.score.as_ref()))
+            .collect();
+        // Games without a kickoff time go last rather than sorting as the earliest
+        games.sort_by_key(|game| (game.start_time.is_none(), game.start_time));
+
+        Ok(games)
+    }
+
+    async fn fetch_game_boxscore(&self, game_id: u64, season: &str) -> Result<Boxscore> {
+        let boxscore_data: BoxscoreResponse = self
+            .get_feed(
+                &format!("{season}/games/{game_id}/boxscore.json"),
+                &[],
+                &format!("boxscore for game {game_id}"),
+            )
+            .await
+            .map_err(game_not_found(game_id))?;
+
+        if boxscore_data.game.id.is_none() {
+            return Err(StatbookError::GameNotFound { game_id });
+        }
+
+        Ok(Boxscore::from_parsed(&boxscore_data))
+    }
+
+    async fn fetch_play_by_play(&self, game_id: u64, season: &str) -> Result<Vec<Play>> {
+        let play_data: PlayByPlayResponse = self
+            .get_feed(
+                &format!("{season}/games/{game_id}/playbyplay.json"),
+                &[],
+                &format!("play-by-play for game {game_id}"),
+            )
+            .await
+            .map_err(game_not_found(game_id))?;
+
+        if play_data.game.id.is_none() {
+            return Err(StatbookError::GameNotFound { game_id });
+        }
+
+        Ok(play_data
+            .plays
+            .iter()
+            .enumerate()
+            .map(|(sequence, entry)| Play::from_parsed(sequence as u32, entry))
+            .collect())
+    }
+
+    async fn fetch_injuries(&self, team: Option<&str>) -> Result<Vec<InjuredPlayer>> {
+        let query: Vec<(&str, &str)> = team.map(|team| ("team", team)).into_iter().collect();
+        let injury_data: InjuriesResponse = self
+            .get_feed("injuries.json", &query, "injury report")
+            .await?;
+        let last_updated = injury_data.last_updated_on.as_deref();
+
+        Ok(injury_data
+            .players
+            .iter()
+            .filter_map(|entry| {
+                Some(InjuredPlayer {
+                    player: PlayerCandidate::from_parsed(&entry.player_info),
+                    injury: Injury::from_parsed(&entry.player_info, last_updated)?,
+                })
+            })
+            .collect())
+    }
+
+    async fn fetch_standings(&self, season: &str) -> Result<Standings> {
+        let standings_data: StandingsResponse = self
+            .get_feed(
+                &format!("{season}/standings.json"),
+                &[],
+                &format!("standings for '{season}'"),
+            )
+            .await?;
+
+        Ok(Standings {
+            teams: standings_data
+                .teams
+                .iter()
+                .map(|entry| TeamStanding::from_parsed(entry, Standings::PLAYOFF_SPOTS))
+                .collect(),
             season: season.to_string(),
         })
     }
+
+    async fn fetch_depth_chart(
+        &self,
+        team: &str,
+        game_id: u64,
+        season: &str,
+    ) -> Result<DepthChart> {
+        let lineup_data: LineupResponse = self
+            .get_feed(
+                &format!("{season}/games/{game_id}/lineup.json"),
+                &[("team", team)],
+                &format!("lineup for '{team}' in game {game_id}"),
+            )
+            .await
+            .map_err(game_not_found(game_id))?;
+
+        if lineup_data.game.id.is_none() {
+            return Err(StatbookError::GameNotFound { game_id });
+        }
+
+        lineup_data
+            .team_lineups
+            .iter()
+            .find(|lineup| {
+                lineup
+                    .team
+                    .abbreviation
+                    .as_deref()
+                    .is_some_and(|abbreviation| abbreviation.eq_ignore_ascii_case(team))
+            })
+            .map(|lineup| DepthChart::from_parsed(game_id, lineup))
+            .ok_or_else(|| StatbookError::TeamNotFound {
+                team: team.to_string(),
+            })
+    }
+
+    async fn fetch_game_lines(&self, game_id: u64, season: &str) -> Result<Vec<GameLine>> {
+        let id = game_id.to_string();
+        let odds_data: GameLinesResponse = self
+            .get_feed(
+                &format!("{season}/odds_gamelines.json"),
+                &[("game", id.as_str())],
+                &format!("game lines for game {game_id}"),
+            )
+            .await?;
+
+        Ok(odds_data
+            .game_lines
+            .iter()
+            .flat_map(|entry| {
+                entry
+                    .lines
+                    .iter()
+                    .map(move |lines| GameLine::from_parsed(entry, lines))
+            })
+            .collect())
+    }
+
+    async fn fetch_transactions(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        team: Option<&str>,
+    ) -> Result<Vec<Transaction>> {
+        let date = format!("from-{}-to-{}", from.format("%Y%m%d"), to.format("%Y%m%d"));
+        let mut query = vec![("date", date.as_str())];
+        if let Some(team) = team {
+            query.push(("team", team));
+        }
+
+        let transaction_data: TransactionsResponse = self
+            .get_feed("player_transactions.json", &query, "player transactions")
+            .await?;
+
+        let mut transactions: Vec<Transaction> = transaction_data
+            .transactions
+            .iter()
+            .filter_map(Transaction::from_parsed)
+            .collect();
+        transactions.sort_by_key(|transaction| transaction.date);
+
+        Ok(transactions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_not_found_maps_only_404() {
+        let missing = game_not_found(133401)(StatbookError::StatsApi {
+            status: 404,
+            message: "Failed to fetch boxscore for game 133401".to_string(),
+        });
+        assert!(matches!(
+            missing,
+            StatbookError::GameNotFound { game_id: 133401 }
+        ));
+
+        let unauthorized = game_not_found(133401)(StatbookError::StatsApi {
+            status: 401,
+            message: "Failed to fetch boxscore for game 133401".to_string(),
+        });
+        assert!(matches!(
+            unauthorized,
+            StatbookError::StatsApi { status: 401, .. }
+        ));
+    }
 }
@@ -1,6 +1,182 @@
+use chrono::{DateTime, Utc};
+
 pub(crate) fn to_dash_case(s: &str) -> String {
     s.split_whitespace()
         .collect::<Vec<&str>>()
         .join("-")
         .to_lowercase()
 }
+
+/// Parses a height such as `6'5"` or `6' 5"` into total inches.
+pub(crate) fn parse_height_inches(height: &str) -> Option<u32> {
+    let (feet, inches) = height.split_once('\'')?;
+    let feet: u32 = feet.trim().parse().ok()?;
+    let inches = inches.trim().trim_end_matches('"').trim();
+    let inches: u32 = if inches.is_empty() {
+        0
+    } else {
+        inches.parse().ok()?
+    };
+
+    Some(feet * 12 + inches)
+}
+
+/// Parses an RFC 3339 timestamp (e.g., "2024-09-08T17:00:00.000Z") into UTC.
+pub(crate) fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+/// Full names of NFL teams keyed by abbreviation.
+const NFL_TEAM_NAMES: [(&str, &str); 33] = [
+    ("ARI", "Arizona Cardinals"),
+    ("ATL", "Atlanta Falcons"),
+    ("BAL", "Baltimore Ravens"),
+    ("BUF", "Buffalo Bills"),
+    ("CAR", "Carolina Panthers"),
+    ("CHI", "Chicago Bears"),
+    ("CIN", "Cincinnati Bengals"),
+    ("CLE", "Cleveland Browns"),
+    ("DAL", "Dallas Cowboys"),
+    ("DEN", "Denver Broncos"),
+    ("DET", "Detroit Lions"),
+    ("GB", "Green Bay Packers"),
+    ("HOU", "Houston Texans"),
+    ("IND", "Indianapolis Colts"),
+    ("JAX", "Jacksonville Jaguars"),
+    ("KC", "Kansas City Chiefs"),
+    ("LA", "Los Angeles Rams"),
+    ("LAR", "Los Angeles Rams"),
+    ("LAC", "Los Angeles Chargers"),
+    ("LV", "Las Vegas Raiders"),
+    ("MIA", "Miami Dolphins"),
+    ("MIN", "Minnesota Vikings"),
+    ("NE", "New England Patriots"),
+    ("NO", "New Orleans Saints"),
+    ("NYG", "New York Giants"),
+    ("NYJ", "New York Jets"),
+    ("PHI", "Philadelphia Eagles"),
+    ("PIT", "Pittsburgh Steelers"),
+    ("SEA", "Seattle Seahawks"),
+    ("SF", "San Francisco 49ers"),
+    ("TB", "Tampa Bay Buccaneers"),
+    ("TEN", "Tennessee Titans"),
+    ("WAS", "Washington Commanders"),
+];
+
+/// Looks up a team's full name (e.g., "Buffalo Bills") from its abbreviation.
+pub(crate) fn nfl_team_name(abbreviation: &str) -> Option<&'static str> {
+    NFL_TEAM_NAMES
+        .iter()
+        .find(|(abbr, _)| abbr.eq_ignore_ascii_case(abbreviation))
+        .map(|(_, name)| *name)
+}
+
+/// Name suffixes ignored when comparing player names.
+const NAME_SUFFIXES: [&str; 6] = ["jr", "sr", "ii", "iii", "iv", "v"];
+
+/// Normalizes a player name for fuzzy comparison.
+///
+/// Lowercases, folds accented Latin characters to ASCII, drops apostrophes
+/// and periods (so "Ja'Marr" and "A.J." collapse to "jamarr" and "aj"),
+/// treats hyphens and dashes as spaces, and removes generational suffixes.
+pub(crate) fn normalize_name(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for c in name.to_lowercase().chars() {
+        match c {
+            '\'' | '\u{2019}' | '.' => {}
+            '-' | '_' => folded.push(' '),
+            c if c.is_ascii_alphanumeric() || c.is_whitespace() => folded.push(c),
+            c => folded.push_str(fold_accent(c)),
+        }
+    }
+
+    folded
+        .split_whitespace()
+        .filter(|token| !NAME_SUFFIXES.contains(token))
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Maps common accented Latin characters to their ASCII equivalents.
+///
+/// Characters without a mapping are dropped.
+fn fold_accent(c: char) -> &'static str {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ł' | 'ľ' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'ř' => "r",
+        'ś' | 'š' | 'ş' => "s",
+        'ť' | 'ţ' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        'ß' => "ss",
+        'æ' => "ae",
+        'œ' => "oe",
+        _ => "",
+    }
+}
+
+/// Similarity between two strings in the range 0.0-1.0, based on
+/// Levenshtein edit distance relative to the longer string.
+pub(crate) fn similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    1.0 - previous[b.len()] as f64 / longest as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("Ja'Marr Chase"), "jamarr chase");
+        assert_eq!(normalize_name("A.J. Brown"), "aj brown");
+        assert_eq!(normalize_name("Amon-Ra St. Brown"), "amon ra st brown");
+        assert_eq!(normalize_name("Odell Beckham Jr."), "odell beckham");
+        assert_eq!(normalize_name("Michael Pittman III"), "michael pittman");
+        assert_eq!(normalize_name("José Ramírez"), "jose ramirez");
+        assert_eq!(normalize_name("  Josh   ALLEN "), "josh allen");
+        assert_eq!(normalize_name("josh-allen"), "josh allen");
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(similarity("josh allen", "josh allen"), 1.0);
+        assert_eq!(similarity("", ""), 1.0);
+        assert_eq!(similarity("abc", ""), 0.0);
+        assert_eq!(similarity("abc", "xyz"), 0.0);
+        // One substitution in a ten-character name
+        assert!((similarity("josh allen", "josh alken") - 0.9).abs() < 1e-9);
+        assert_eq!(
+            similarity("kadarius", "kadarious"),
+            similarity("kadarious", "kadarius")
+        );
+    }
+}